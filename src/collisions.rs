@@ -0,0 +1,223 @@
+use std::cmp::Ordering;
+
+use ggez::nalgebra::Point2;
+
+use crate::game_objects::{GameObject, GameObjectTypes, GameObjectfeatureTypes};
+use crate::physics_systems::PhysicsState;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CollisionLayer {
+    /// Can't be walked through, physics systems resolve these themselves
+    Solid,
+    /// Only does something while overlapped, ropes, ladders, pits and relics
+    Trigger,
+    /// Costs the player a life on contact
+    Hazard,
+}
+
+/// Axis aligned box around a center, the same way every game object is placed
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Aabb {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl Aabb {
+    pub fn new(center: Point2<f32>, width: f32, height: f32) -> Self {
+        Self {
+            left: center.x - width / 2.0,
+            right: center.x + width / 2.0,
+            top: center.y - height / 2.0,
+            bottom: center.y + height / 2.0,
+        }
+    }
+
+    pub fn of(game_object: &GameObject) -> Self {
+        Self::new(game_object.location, game_object.width, game_object.height)
+    }
+
+    /// Touching edges don't count
+    pub fn overlaps(&self, other: &Aabb) -> bool {
+        self.left < other.right
+            && other.left < self.right
+            && self.top < other.bottom
+            && other.top < self.bottom
+    }
+
+    pub fn overlaps_horizontally(&self, other: &Aabb) -> bool {
+        self.left < other.right && other.left < self.right
+    }
+
+    /// True when we fit strictly between the other box's left and right edges
+    pub fn inside_horizontally(&self, other: &Aabb) -> bool {
+        self.left > other.left && self.right < other.right
+    }
+
+    /// The x our center needs to be at to sit just outside the other box, on whichever side we're on
+    pub fn pushed_out_sideways(&self, other: &Aabb) -> f32 {
+        let half_width = (self.right - self.left) / 2.0;
+
+        if self.left + self.right < other.left + other.right {
+            other.left - half_width
+        } else {
+            other.right + half_width
+        }
+    }
+}
+
+/// What an object is told about something it overlaps
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Collision {
+    pub layer: CollisionLayer,
+    pub my_type: GameObjectTypes,
+    pub feature_type: Option<GameObjectfeatureTypes>,
+    pub physics_state: Option<PhysicsState>,
+    pub location: Point2<f32>,
+    pub bounds: Aabb,
+}
+
+impl Collision {
+    /// None for objects that don't collide with anything, like the background and hearts
+    pub fn with(other: &GameObject) -> Option<Self> {
+        Some(Self {
+            layer: other.collision_layer()?,
+            my_type: other.my_type,
+            feature_type: other.feature_type,
+            physics_state: other.physics_state,
+            location: other.location,
+            bounds: Aabb::of(other),
+        })
+    }
+}
+
+/// Tells both objects in every overlapping pair about each other.
+/// Everything is measured once up front into `colliders`, which is only kept around so its
+/// allocation can be reused next tick, then swept left to right so only neighbours are compared
+pub fn deliver(objects: &mut [GameObject], colliders: &mut Vec<(usize, Collision)>) {
+    colliders.clear();
+    colliders.extend(
+        objects
+            .iter()
+            .enumerate()
+            .filter_map(|(index, object)| Some((index, Collision::with(object)?))),
+    );
+    colliders.sort_unstable_by(|(_, first), (_, second)| {
+        first
+            .bounds
+            .left
+            .partial_cmp(&second.bounds.left)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    for (position, (first_index, first)) in colliders.iter().enumerate() {
+        for (second_index, second) in &colliders[position + 1..] {
+            if second.bounds.left >= first.bounds.right {
+                break;
+            }
+
+            if first.bounds.overlaps(&second.bounds) {
+                objects[*first_index].collided(second);
+                objects[*second_index].collided(first);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+    use crate::game_objects::builders::hazard::create_snake;
+    use crate::game_objects::builders::pit1::create_pit1;
+    use crate::game_objects::builders::player::create_player;
+    use crate::game_objects::game_object::GameObjectBuilder;
+    use crate::life_systems::DeathCause;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_aabb_overlaps() {
+        let aabb = |x: f32, y: f32| Aabb::new(Point2::new(x, y), 10.0, 10.0);
+
+        assert!(aabb(0.0, 0.0).overlaps(&aabb(9.0, 9.0)));
+        assert!(!aabb(0.0, 0.0).overlaps(&aabb(10.0, 0.0)));
+        assert!(!aabb(0.0, 0.0).overlaps(&aabb(0.0, -12.0)));
+        assert!(aabb(0.0, 0.0).overlaps_horizontally(&aabb(5.0, 100.0)));
+    }
+
+    #[test]
+    fn ci_test_aabb_inside_horizontally() {
+        let wide = Aabb::new(Point2::new(0.0, 0.0), 100.0, 0.0);
+
+        assert!(Aabb::new(Point2::new(10.0, 50.0), 20.0, 20.0).inside_horizontally(&wide));
+        assert!(!Aabb::new(Point2::new(45.0, 0.0), 20.0, 20.0).inside_horizontally(&wide));
+    }
+
+    #[test]
+    fn ci_test_aabb_pushed_out_sideways() {
+        let wall = Aabb::new(Point2::new(100.0, 0.0), 20.0, 100.0);
+
+        assert_eq!(
+            Aabb::new(Point2::new(95.0, 0.0), 10.0, 10.0).pushed_out_sideways(&wall),
+            85.0
+        );
+        assert_eq!(
+            Aabb::new(Point2::new(105.0, 0.0), 10.0, 10.0).pushed_out_sideways(&wall),
+            115.0
+        );
+    }
+
+    #[test]
+    fn ci_test_deliver_to_what_overlaps() {
+        let config = config::load("config.json").unwrap();
+        let snake = create_snake(&config).unwrap();
+        let mut player = create_player(&config).unwrap();
+        player.location.x = snake.location.x;
+        let mut pit = create_pit1(&config).unwrap();
+        pit.location.x = 0.0;
+        let background = GameObjectBuilder::new()
+            .location(snake.location)
+            .width(10_000.0)
+            .height(10_000.0)
+            .with_type(GameObjectTypes::Background)
+            .build()
+            .unwrap();
+
+        let objects = &mut [pit, player, snake, background];
+        assert_eq!(Collision::with(&objects[3]), None);
+
+        deliver(objects, &mut vec![]);
+
+        assert_eq!(objects[1].get_lives(), Some(config.player_lives - 1));
+        assert_eq!(objects[1].get_death_cause(), Some(DeathCause::Snake));
+        assert_eq!(
+            Collision::with(&objects[2]).unwrap().layer,
+            CollisionLayer::Hazard
+        );
+    }
+
+    #[test]
+    fn ci_test_deliver_sweeps_past_narrower_neighbours() {
+        let config = config::load("config.json").unwrap();
+        let mut long_snake = create_snake(&config).unwrap();
+        long_snake.location.x = 1000.0;
+        long_snake.width = 2000.0;
+        let mut short_snake = create_snake(&config).unwrap();
+        short_snake.location.x = 500.0;
+        let mut player = create_player(&config).unwrap();
+        player.location.x = 1800.0;
+        player.location.y = long_snake.location.y;
+        let mut far_player = create_player(&config).unwrap();
+        far_player.location.x = 5000.0;
+
+        let objects = &mut [player, short_snake, far_player, long_snake];
+        let colliders = &mut vec![];
+        deliver(objects, colliders);
+
+        assert_eq!(objects[0].get_lives(), Some(config.player_lives - 1));
+        assert_eq!(objects[2].get_lives(), Some(config.player_lives));
+        assert_eq!(colliders.len(), 4);
+    }
+}
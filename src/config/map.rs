@@ -5,4 +5,32 @@ pub enum MapFeature {
     Pit1,
     Pit3,
     Rope,
+    Ladder,
+    TarPit,
+    Lake,
+    /// A lake with a row of alligator heads to hop across
+    Alligators,
+    Snake,
+    Fire,
+    /// How many barrels roll across the screen
+    Barrels(usize),
+    /// Index into the relic catalog in the config
+    Relic(usize),
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+pub enum WallSide {
+    Left,
+    Right,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub enum CaveFeature {
+    BrickWall(WallSide),
+    /// Ladder back up to the surface, the index picks which of the surface screens above the cave screen it comes out on
+    Exit(usize),
+    Scorpion,
+    /// Pixels from the left edge of the screen
+    Stalagmite(u32),
+    Stalactite(u32),
 }
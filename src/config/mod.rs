@@ -1,21 +1,69 @@
 mod map;
+mod relic;
 mod serde_button;
 mod serde_color;
+mod serde_key;
 
+use ggez::event::{Button, KeyCode};
+use ggez::graphics::Color;
 use ggez::graphics::BLACK;
-use ggez::{event::Button, graphics::Color};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 
-pub use self::map::MapFeature;
+pub use self::map::{CaveFeature, MapFeature, WallSide};
+pub use self::relic::Relic;
 
 #[derive(PartialEq, Debug, Serialize, Deserialize)]
 pub struct Config {
+    pub alligator_closed_ticks: u32,
+    #[serde(with = "crate::config::serde_color")]
+    pub alligator_color: Color,
+    pub alligator_count: usize,
+    pub alligator_height: f32,
+    #[serde(with = "crate::config::serde_color")]
+    pub alligator_mouth_color: Color,
+    pub alligator_open_ticks: u32,
+    pub alligator_spacing: f32,
+    pub alligator_width: f32,
+    pub barrel_height: f32,
+    pub barrel_knockback: f32,
+    pub barrel_penalty: u32,
+    pub barrel_spacing: f32,
+    pub barrel_spritesheet: String,
+    pub barrel_spritesheet_count: f32,
+    pub barrel_velocity: f32,
+    pub barrel_width: f32,
     #[serde(with = "crate::config::serde_color")]
     pub bedrock_color: Color,
     pub bedrock_height: f32,
     pub bedrock_image: String,
+    #[serde(with = "crate::config::serde_color")]
+    pub brick_color: Color,
+    pub brick_height: f32,
+    #[serde(with = "crate::config::serde_color")]
+    pub brick_mortar_color: Color,
+    pub brick_wall_width: f32,
     pub cave_height: f32,
+    /// One entry per cave screen, each cave screen lies under `cave_screen_span` surface screens
+    pub cave_map: Vec<Vec<CaveFeature>>,
+    #[serde(with = "crate::config::serde_color")]
+    pub cave_rock_color: Color,
+    pub cave_screen_span: usize,
+    #[serde(with = "crate::config::serde_button")]
+    pub climb_down_buttons: Vec<Button>,
+    #[serde(with = "crate::config::serde_key")]
+    pub climb_down_keys: Vec<KeyCode>,
+    pub climb_speed: f32,
+    #[serde(with = "crate::config::serde_button")]
+    pub climb_up_buttons: Vec<Button>,
+    #[serde(with = "crate::config::serde_key")]
+    pub climb_up_keys: Vec<KeyCode>,
+    pub fire_height: f32,
+    pub fire_hitbox_height: f32,
+    pub fire_hitbox_width: f32,
+    pub fire_spritesheet: String,
+    pub fire_spritesheet_count: f32,
+    pub fire_width: f32,
     #[serde(with = "crate::config::serde_color")]
     pub foliage_color: Color,
     pub foliage_points: u8,
@@ -23,25 +71,59 @@ pub struct Config {
     pub font_large: f32,
     pub font_medium: f32,
     pub font_small: f32,
+    pub freeplay: bool,
     pub gravity_force: f32,
     #[serde(with = "crate::config::serde_color")]
     pub ground_color: Color,
     pub ground_height: f32,
-    #[serde(with = "crate::config::serde_button")]
-    pub jump_button: Button,
+    /// Where snakes and fires sit on their screen
+    pub hazard_x: f32,
+    pub heart_flash_frames: u8,
+    #[serde(with = "crate::config::serde_button", alias = "jump_button")]
+    pub jump_buttons: Vec<Button>,
     pub jump_force: f32,
+    #[serde(with = "crate::config::serde_key", alias = "jump_key")]
+    pub jump_keys: Vec<KeyCode>,
+    #[serde(with = "crate::config::serde_color")]
+    pub ladder_color: Color,
+    pub ladder_rung_spacing: f32,
+    pub ladder_width: f32,
+    #[serde(with = "crate::config::serde_color")]
+    pub lake_color: Color,
     pub life_image: String,
     pub life_width: f32,
     pub map: Vec<Vec<MapFeature>>,
     #[serde(with = "crate::config::serde_button")]
-    pub move_left_button: Button,
+    pub menu_down_buttons: Vec<Button>,
+    #[serde(with = "crate::config::serde_key")]
+    pub menu_down_keys: Vec<KeyCode>,
     #[serde(with = "crate::config::serde_button")]
-    pub move_right_button: Button,
+    pub menu_select_buttons: Vec<Button>,
+    #[serde(with = "crate::config::serde_key")]
+    pub menu_select_keys: Vec<KeyCode>,
+    #[serde(with = "crate::config::serde_button")]
+    pub menu_up_buttons: Vec<Button>,
+    #[serde(with = "crate::config::serde_key")]
+    pub menu_up_keys: Vec<KeyCode>,
+    #[serde(with = "crate::config::serde_button", alias = "move_left_button")]
+    pub move_left_buttons: Vec<Button>,
+    #[serde(with = "crate::config::serde_key", alias = "move_left_key")]
+    pub move_left_keys: Vec<KeyCode>,
+    #[serde(with = "crate::config::serde_button", alias = "move_right_button")]
+    pub move_right_buttons: Vec<Button>,
+    #[serde(with = "crate::config::serde_key", alias = "move_right_key")]
+    pub move_right_keys: Vec<KeyCode>,
+    #[serde(with = "crate::config::serde_button")]
+    pub pause_buttons: Vec<Button>,
+    #[serde(with = "crate::config::serde_key")]
+    pub pause_keys: Vec<KeyCode>,
+    pub pit3_spacing: f32,
     #[serde(with = "crate::config::serde_color")]
     pub pit_color: Color,
     pub pit_height: f32,
     pub pit_width: f32,
     pub player_height: f32,
+    pub player_invulnerable_ticks: u16,
     pub player_lives: u8,
     pub player_running_spritesheet_count: f32,
     pub player_running_spritesheet: String,
@@ -52,19 +134,58 @@ pub struct Config {
     pub player_starting_x: f32,
     pub player_starting_y: f32,
     pub player_width: f32,
+    /// Tar pits and lakes grow from the min to the max width and back once per cycle
+    pub pool_cycle_ticks: u32,
+    pub pool_max_width: f32,
+    pub pool_min_width: f32,
+    pub relic_height: f32,
+    pub relic_width: f32,
+    pub relics: Vec<Relic>,
     pub resolution_x: f32,
     pub resolution_y: f32,
+    pub rope_anchor_y: f32,
+    #[serde(with = "crate::config::serde_color")]
+    pub rope_color: Color,
+    pub rope_grab_size: f32,
+    pub rope_length: f32,
+    pub rope_max_angle: f32,
+    pub rope_width: f32,
+    pub score_per_second: u32,
+    pub scorpion_height: f32,
+    pub scorpion_hitbox_height: f32,
+    pub scorpion_hitbox_width: f32,
+    pub scorpion_speed: f32,
+    pub scorpion_spritesheet: String,
+    pub scorpion_spritesheet_count: f32,
+    pub scorpion_width: f32,
     #[serde(with = "crate::config::serde_color")]
     pub sky_color: Color,
+    pub snake_height: f32,
+    pub snake_hitbox_height: f32,
+    pub snake_hitbox_width: f32,
+    pub snake_spritesheet: String,
+    pub snake_spritesheet_count: f32,
+    pub snake_width: f32,
     pub spritesheet_animation_speed: u8,
-    #[serde(with = "crate::config::serde_button")]
-    pub start_button: Button,
+    pub stalactite_height: f32,
+    pub stalactite_width: f32,
+    pub stalagmite_height: f32,
+    pub stalagmite_width: f32,
+    #[serde(with = "crate::config::serde_button", alias = "start_button")]
+    pub start_buttons: Vec<Button>,
     pub start_index: usize,
+    #[serde(with = "crate::config::serde_key", alias = "start_key")]
+    pub start_keys: Vec<KeyCode>,
+    pub stick_dead_zone: f32,
+    pub stick_hysteresis: f32,
     pub surface_bottom_height: f32,
     #[serde(with = "crate::config::serde_color")]
     pub surface_color: Color,
     pub surface_floor_y: f32,
     pub surface_top_height: f32,
+    #[serde(with = "crate::config::serde_color")]
+    pub tar_pit_color: Color,
+    pub timer_seconds: u32,
     pub title_subtext: String,
     pub title: String,
     pub tree_branch_height: f32,
@@ -82,30 +203,84 @@ pub struct Config {
 impl Default for Config {
     fn default() -> Self {
         Self {
+            alligator_closed_ticks: 90,
+            alligator_color: Color::from_rgb(56, 120, 40),
+            alligator_count: 3,
+            alligator_height: 30.0,
+            alligator_mouth_color: Color::from_rgb(200, 60, 60),
+            alligator_open_ticks: 60,
+            alligator_spacing: 140.0,
+            alligator_width: 90.0,
+            barrel_height: 64.0,
+            barrel_knockback: 12.0,
+            barrel_penalty: 100,
+            barrel_spacing: 200.0,
+            barrel_spritesheet: "/barrel_rolling.png".to_owned(),
+            barrel_spritesheet_count: 2.0,
+            barrel_velocity: -5.0,
+            barrel_width: 64.0,
             bedrock_color: Color::from_rgb(147, 94, 59),
             bedrock_height: 15.0,
             bedrock_image: "/bedrock.png".to_owned(),
+            brick_color: Color::from_rgb(168, 72, 40),
+            brick_height: 25.0,
+            brick_mortar_color: Color::from_rgb(142, 142, 142),
+            brick_wall_width: 60.0,
             cave_height: 200.0,
+            cave_map: vec![vec![]],
+            cave_rock_color: Color::from_rgb(110, 98, 86),
+            cave_screen_span: 1,
+            climb_down_buttons: vec![Button::DPadDown],
+            climb_down_keys: vec![KeyCode::Down],
+            climb_speed: 5.0,
+            climb_up_buttons: vec![Button::DPadUp],
+            climb_up_keys: vec![KeyCode::Up],
+            fire_height: 64.0,
+            fire_hitbox_height: 40.0,
+            fire_hitbox_width: 30.0,
+            fire_spritesheet: "/fire.png".to_owned(),
+            fire_spritesheet_count: 3.0,
+            fire_width: 64.0,
             foliage_color: Color::from_rgb(33, 43, 18),
             foliage_points: 50,
             foliage_step_vertical: 15.0,
             font_large: 72.0,
             font_medium: 55.0,
             font_small: 36.0,
+            freeplay: false,
             gravity_force: 1.0,
             ground_color: Color::from_rgb(146, 137, 60),
             ground_height: 50.0,
-            jump_button: Button::South,
+            hazard_x: 1250.0,
+            heart_flash_frames: 30,
+            jump_buttons: vec![Button::South],
             jump_force: 1.0,
+            jump_keys: vec![KeyCode::Space],
+            ladder_color: Color::from_rgb(74, 69, 31),
+            ladder_rung_spacing: 30.0,
+            ladder_width: 80.0,
+            lake_color: Color::from_rgb(24, 26, 167),
             life_image: "/heart.png".to_owned(),
             life_width: 32.0,
             map: vec![vec![MapFeature::Pit1]],
-            move_left_button: Button::DPadLeft,
-            move_right_button: Button::DPadRight,
+            menu_down_buttons: vec![Button::DPadDown],
+            menu_down_keys: vec![KeyCode::Down],
+            menu_select_buttons: vec![Button::South],
+            menu_select_keys: vec![KeyCode::Return],
+            menu_up_buttons: vec![Button::DPadUp],
+            menu_up_keys: vec![KeyCode::Up],
+            move_left_buttons: vec![Button::DPadLeft],
+            move_left_keys: vec![KeyCode::Left],
+            move_right_buttons: vec![Button::DPadRight],
+            move_right_keys: vec![KeyCode::Right],
+            pause_buttons: vec![Button::Start],
+            pause_keys: vec![KeyCode::P, KeyCode::Escape],
+            pit3_spacing: 150.0,
             pit_color: BLACK,
             pit_height: 100.0,
             pit_width: 125.0,
             player_height: 160.0,
+            player_invulnerable_ticks: 60,
             player_lives: 7,
             player_running_spritesheet_count: 5.0,
             player_running_spritesheet: "jungle_player_running-Sheet.png".to_owned(),
@@ -116,16 +291,67 @@ impl Default for Config {
             player_starting_x: 150.0,
             player_starting_y: 710.0,
             player_width: 80.0,
+            pool_cycle_ticks: 180,
+            pool_max_width: 500.0,
+            pool_min_width: 0.0,
+            relic_height: 64.0,
+            relic_width: 64.0,
+            relics: vec![
+                Relic {
+                    name: "Golden idol".to_owned(),
+                    image: "/relic_idol.png".to_owned(),
+                    points: 1000,
+                },
+                Relic {
+                    name: "Jade mask".to_owned(),
+                    image: "/relic_mask.png".to_owned(),
+                    points: 1000,
+                },
+                Relic {
+                    name: "Ruby amulet".to_owned(),
+                    image: "/relic_amulet.png".to_owned(),
+                    points: 1000,
+                },
+            ],
             resolution_x: 1920.0,
             resolution_y: 1080.0,
+            rope_anchor_y: 250.0,
+            rope_color: Color::from_rgb(74, 69, 31),
+            rope_grab_size: 40.0,
+            rope_length: 350.0,
+            rope_max_angle: 0.8,
+            rope_width: 6.0,
+            score_per_second: 1000,
+            scorpion_height: 48.0,
+            scorpion_hitbox_height: 30.0,
+            scorpion_hitbox_width: 50.0,
+            scorpion_speed: 3.0,
+            scorpion_spritesheet: "/scorpion.png".to_owned(),
+            scorpion_spritesheet_count: 2.0,
+            scorpion_width: 64.0,
             sky_color: Color::from_rgb(164, 196, 112),
+            snake_height: 64.0,
+            snake_hitbox_height: 24.0,
+            snake_hitbox_width: 40.0,
+            snake_spritesheet: "/snake.png".to_owned(),
+            snake_spritesheet_count: 2.0,
+            snake_width: 64.0,
             spritesheet_animation_speed: 10,
-            start_button: Button::Start,
+            stalactite_height: 30.0,
+            stalactite_width: 50.0,
+            stalagmite_height: 30.0,
+            stalagmite_width: 60.0,
+            start_buttons: vec![Button::Start],
             start_index: 0,
+            start_keys: vec![KeyCode::Return],
+            stick_dead_zone: 0.5,
+            stick_hysteresis: 0.2,
             surface_bottom_height: 25.0,
             surface_color: Color::from_rgb(189, 179, 94),
             surface_floor_y: 790.0,
             surface_top_height: 125.0,
+            tar_pit_color: Color::from_rgb(20, 20, 20),
+            timer_seconds: 1200,
             title_subtext: "Press start to begin".to_owned(),
             title: "Jungle".to_owned(),
             tree_branch_height: 50.0,
@@ -141,12 +367,80 @@ impl Default for Config {
     }
 }
 
+fn deserialize_names<'de, D>(deser: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deser)? {
+        OneOrMany::One(name) => vec![name],
+        OneOrMany::Many(names) => names,
+    })
+}
+
 pub fn load(file_name: &str) -> eyre::Result<Config> {
-    let config = serde_json::from_reader(File::open(file_name)?)?;
+    let config: Config = serde_json::from_reader(File::open(file_name)?)?;
+    validate_relics(&config)?;
+    validate_cave_map(&config)?;
 
     Ok(config)
 }
 
+fn validate_relics(config: &Config) -> eyre::Result<()> {
+    let missing_relic = config
+        .map
+        .iter()
+        .flatten()
+        .find(|map_feature| match map_feature {
+            MapFeature::Relic(relic_index) => *relic_index >= config.relics.len(),
+            _ => false,
+        });
+
+    if let Some(missing_relic) = missing_relic {
+        eyre::bail!("map uses {:?} but there's no such relic", missing_relic);
+    }
+
+    Ok(())
+}
+
+fn validate_cave_map(config: &Config) -> eyre::Result<()> {
+    if config.cave_screen_span == 0
+        || config.cave_map.len() * config.cave_screen_span != config.map.len()
+    {
+        eyre::bail!(
+            "{} cave screens spanning {} surface screens each don't cover the {} surface screens",
+            config.cave_map.len(),
+            config.cave_screen_span,
+            config.map.len()
+        );
+    }
+
+    let bad_exit = config
+        .cave_map
+        .iter()
+        .flatten()
+        .find(|cave_feature| match cave_feature {
+            CaveFeature::Exit(offset) => *offset >= config.cave_screen_span,
+            _ => false,
+        });
+
+    if let Some(bad_exit) = bad_exit {
+        eyre::bail!(
+            "cave map uses {:?} but cave screens only span {} surface screens",
+            bad_exit,
+            config.cave_screen_span
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -163,4 +457,60 @@ mod test {
 
         assert_eq!(config.tree_trunk_height, 300.0)
     }
+
+    #[test]
+    fn ci_test_map_relics_must_be_in_the_catalog() {
+        let mut config = Config::default();
+        config.map = vec![vec![MapFeature::Relic(config.relics.len())]];
+
+        assert!(validate_relics(&config).is_err());
+        config.map = vec![vec![MapFeature::Relic(0)]];
+        assert!(validate_relics(&config).is_ok());
+    }
+
+    #[test]
+    fn ci_test_cave_map_must_cover_the_surface() {
+        let mut config = Config::default();
+        assert!(validate_cave_map(&config).is_ok());
+
+        config.cave_screen_span = 2;
+        assert!(validate_cave_map(&config).is_err());
+        config.map = vec![vec![], vec![]];
+        assert!(validate_cave_map(&config).is_ok());
+
+        config.cave_map = vec![vec![CaveFeature::Exit(2)]];
+        assert!(validate_cave_map(&config).is_err());
+        config.cave_map = vec![vec![CaveFeature::Exit(1)]];
+        assert!(validate_cave_map(&config).is_ok());
+    }
+
+    #[test]
+    fn ci_test_bindings_accept_one_or_many_names() {
+        let mut config = serde_json::to_value(Config::default()).unwrap();
+        config["jump_buttons"] = serde_json::json!(["South", "East"]);
+        config["start_keys"] = serde_json::json!("Space");
+        let config: Config = serde_json::from_value(config).unwrap();
+
+        assert_eq!(config.jump_buttons, vec![Button::South, Button::East]);
+        assert_eq!(config.start_keys, vec![KeyCode::Space]);
+    }
+
+    #[test]
+    fn ci_test_old_single_binding_names_still_load() {
+        let mut config = serde_json::to_value(Config::default()).unwrap();
+        let config_object = config.as_object_mut().unwrap();
+        config_object.remove("jump_buttons");
+        config_object.insert("jump_button".to_owned(), serde_json::json!("North"));
+        let config: Config = serde_json::from_value(config).unwrap();
+
+        assert_eq!(config.jump_buttons, vec![Button::North]);
+    }
+
+    #[test]
+    fn ci_test_unknown_binding_fails_to_load() {
+        let mut config = serde_json::to_value(Config::default()).unwrap();
+        config["move_left_keys"] = serde_json::json!(["Left", "Hyper"]);
+
+        assert!(serde_json::from_value::<Config>(config).is_err());
+    }
 }
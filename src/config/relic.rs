@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Relic {
+    pub name: String,
+    pub image: String,
+    pub points: u32,
+}
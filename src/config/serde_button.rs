@@ -1,29 +1,89 @@
 use ggez::event::Button;
 
-pub fn serialize<S>(button: &Button, ser: S) -> Result<S::Ok, S::Error>
+macro_rules! button_names {
+    ($($button:ident),* $(,)?) => {
+        fn button_to_name(button: &Button) -> &'static str {
+            match button {
+                $(Button::$button => stringify!($button),)*
+            }
+        }
+
+        fn name_to_button(name: &str) -> Option<Button> {
+            match name {
+                $(stringify!($button) => Some(Button::$button),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+button_names!(
+    South,
+    East,
+    North,
+    West,
+    C,
+    Z,
+    LeftTrigger,
+    LeftTrigger2,
+    RightTrigger,
+    RightTrigger2,
+    Select,
+    Start,
+    Mode,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    Unknown,
+);
+
+pub fn serialize<S>(buttons: &[Button], ser: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
-    match button {
-        Button::Start => ser.serialize_str("Start"),
-        Button::DPadRight => ser.serialize_str("DPadRight"),
-        Button::DPadLeft => ser.serialize_str("DPadLeft"),
-        Button::South => ser.serialize_str("South"),
-        _ => Err(serde::ser::Error::custom("unknown button pressed")),
-    }
+    ser.collect_seq(buttons.iter().map(button_to_name))
 }
 
-pub fn deserialize<'de, D>(deser: D) -> Result<Button, D::Error>
+/// Accepts either a single button name or a list of them so that older
+/// configs binding one button per action still load.
+pub fn deserialize<'de, D>(deser: D) -> Result<Vec<Button>, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
-    use serde::Deserialize as _;
-    let button_string = <String>::deserialize(deser)?;
-    match button_string.as_str() {
-        "Start" => Ok(Button::Start),
-        "DPadRight" => Ok(Button::DPadRight),
-        "DPadLeft" => Ok(Button::DPadLeft),
-        "South" => Ok(Button::South),
-        _ => Err(serde::de::Error::custom("Unknown button")),
+    super::deserialize_names(deser)?
+        .iter()
+        .map(|button_string| {
+            name_to_button(button_string).ok_or_else(|| {
+                serde::de::Error::custom(format!("Unknown button {}", button_string))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ci_test_every_button_round_trips_by_name() {
+        let buttons = [
+            Button::South,
+            Button::LeftTrigger2,
+            Button::Mode,
+            Button::RightThumb,
+            Button::DPadUp,
+        ];
+
+        for button in buttons.iter() {
+            assert_eq!(name_to_button(button_to_name(button)), Some(*button));
+        }
+    }
+
+    #[test]
+    fn ci_test_unknown_button_name() {
+        assert_eq!(name_to_button("Turbo"), None);
     }
 }
@@ -0,0 +1,229 @@
+use ggez::event::KeyCode;
+
+macro_rules! key_names {
+    ($($key:ident),* $(,)?) => {
+        fn key_to_name(key: &KeyCode) -> &'static str {
+            match key {
+                $(KeyCode::$key => stringify!($key),)*
+            }
+        }
+
+        fn name_to_key(name: &str) -> Option<KeyCode> {
+            match name {
+                $(stringify!($key) => Some(KeyCode::$key),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+key_names!(
+    Key1,
+    Key2,
+    Key3,
+    Key4,
+    Key5,
+    Key6,
+    Key7,
+    Key8,
+    Key9,
+    Key0,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Escape,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    Snapshot,
+    Scroll,
+    Pause,
+    Insert,
+    Home,
+    Delete,
+    End,
+    PageDown,
+    PageUp,
+    Left,
+    Up,
+    Right,
+    Down,
+    Back,
+    Return,
+    Space,
+    Compose,
+    Caret,
+    Numlock,
+    Numpad0,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+    AbntC1,
+    AbntC2,
+    Add,
+    Apostrophe,
+    Apps,
+    At,
+    Ax,
+    Backslash,
+    Calculator,
+    Capital,
+    Colon,
+    Comma,
+    Convert,
+    Decimal,
+    Divide,
+    Equals,
+    Grave,
+    Kana,
+    Kanji,
+    LAlt,
+    LBracket,
+    LControl,
+    LShift,
+    LWin,
+    Mail,
+    MediaSelect,
+    MediaStop,
+    Minus,
+    Multiply,
+    Mute,
+    MyComputer,
+    NavigateForward,
+    NavigateBackward,
+    NextTrack,
+    NoConvert,
+    NumpadComma,
+    NumpadEnter,
+    NumpadEquals,
+    OEM102,
+    Period,
+    PlayPause,
+    Power,
+    PrevTrack,
+    RAlt,
+    RBracket,
+    RControl,
+    RShift,
+    RWin,
+    Semicolon,
+    Slash,
+    Sleep,
+    Stop,
+    Subtract,
+    Sysrq,
+    Tab,
+    Underline,
+    Unlabeled,
+    VolumeDown,
+    VolumeUp,
+    Wake,
+    WebBack,
+    WebFavorites,
+    WebForward,
+    WebHome,
+    WebRefresh,
+    WebSearch,
+    WebStop,
+    Yen,
+    Copy,
+    Paste,
+    Cut,
+);
+
+pub fn serialize<S>(keys: &[KeyCode], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    ser.collect_seq(keys.iter().map(key_to_name))
+}
+
+/// Accepts either a single key name or a list of them, matching how buttons
+/// are bound.
+pub fn deserialize<'de, D>(deser: D) -> Result<Vec<KeyCode>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    super::deserialize_names(deser)?
+        .iter()
+        .map(|key_string| {
+            name_to_key(key_string)
+                .ok_or_else(|| serde::de::Error::custom(format!("Unknown key {}", key_string)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ci_test_keys_round_trip_by_name() {
+        let keys = [
+            KeyCode::Key1,
+            KeyCode::W,
+            KeyCode::Space,
+            KeyCode::Numpad5,
+            KeyCode::Cut,
+        ];
+
+        for key in keys.iter() {
+            assert_eq!(name_to_key(key_to_name(key)), Some(*key));
+        }
+    }
+
+    #[test]
+    fn ci_test_unknown_key_name() {
+        assert_eq!(name_to_key("Hyper"), None);
+    }
+}
@@ -0,0 +1,119 @@
+use ggez::graphics::{self, DrawMode, DrawParam, Mesh, MeshBuilder, Rect};
+use ggez::nalgebra::Point2;
+use ggez::{Context, GameResult};
+
+use crate::config::Config;
+use crate::physics_systems::PhysicsState;
+
+use super::DrawSystem;
+
+pub struct AlligatorDrawSystem {
+    closed: Option<Mesh>,
+    open: Option<Mesh>,
+}
+
+impl AlligatorDrawSystem {
+    pub fn new() -> Self {
+        Self {
+            closed: None,
+            open: None,
+        }
+    }
+
+    /// The head is centered on the origin with the snout pointing right, open jaws swing the top half up
+    fn build_head(context: &mut Context, config: &Config, open: bool) -> GameResult<Mesh> {
+        let half_width = config.alligator_width / 2.0;
+        let half_height = config.alligator_height / 2.0;
+        let mut mesh = MeshBuilder::new();
+
+        mesh.rectangle(
+            DrawMode::fill(),
+            Rect::new(-half_width, 0.0, config.alligator_width, half_height),
+            config.alligator_color,
+        );
+
+        if open {
+            mesh.polygon(
+                DrawMode::fill(),
+                &[
+                    Point2::new(-half_width, 0.0),
+                    Point2::new(half_width, -config.alligator_height * 1.5),
+                    Point2::new(half_width, 0.0),
+                ],
+                config.alligator_mouth_color,
+            )?
+            .polygon(
+                DrawMode::fill(),
+                &[
+                    Point2::new(-half_width, 0.0),
+                    Point2::new(-half_width, -half_height),
+                    Point2::new(half_width, -config.alligator_height * 1.5),
+                ],
+                config.alligator_color,
+            )?;
+        } else {
+            mesh.rectangle(
+                DrawMode::fill(),
+                Rect::new(
+                    -half_width,
+                    -half_height,
+                    config.alligator_width,
+                    half_height,
+                ),
+                config.alligator_color,
+            );
+        }
+
+        mesh.circle(
+            DrawMode::fill(),
+            Point2::new(-half_width / 2.0, -half_height),
+            half_height / 2.0,
+            0.5,
+            graphics::WHITE,
+        );
+
+        mesh.build(context)
+    }
+}
+
+impl DrawSystem for AlligatorDrawSystem {
+    fn draw(
+        &mut self,
+        _images: &mut crate::images::Images,
+        config: &Config,
+        context: &mut Context,
+        location: &Point2<f32>,
+        physics_state: Option<PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> GameResult {
+        let open = physics_state == Some(PhysicsState::Open);
+        let head = if open {
+            &mut self.open
+        } else {
+            &mut self.closed
+        };
+
+        if head.is_none() {
+            *head = Some(Self::build_head(context, config, open)?);
+        }
+
+        if let Some(head) = head {
+            graphics::draw(context, &*head, DrawParam::new().dest(*location))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ci_test_create_alligator_draw_system() {
+        let alligator_draw_system = AlligatorDrawSystem::new();
+
+        assert!(alligator_draw_system.closed.is_none());
+        assert!(alligator_draw_system.open.is_none());
+    }
+}
@@ -0,0 +1,85 @@
+use ggez::graphics::{DrawParam, Rect};
+
+use crate::config::Config;
+
+use super::DrawSystem;
+
+pub struct BarrelDrawSystem {
+    frames_until_sprite_change: u8,
+    spritesheet_portion: Rect,
+}
+
+impl BarrelDrawSystem {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            frames_until_sprite_change: config.spritesheet_animation_speed,
+            spritesheet_portion: Rect::new(0.0, 0.0, 1.0 / config.barrel_spritesheet_count, 1.0),
+        }
+    }
+
+    fn update(&mut self, config: &Config) {
+        self.frames_until_sprite_change = if self.frames_until_sprite_change == 0 {
+            self.spritesheet_portion.x += 1.0 / config.barrel_spritesheet_count;
+            if self.spritesheet_portion.x >= 1.0 {
+                self.spritesheet_portion.x = 0.0;
+            }
+            config.spritesheet_animation_speed
+        } else {
+            self.frames_until_sprite_change - 1
+        };
+    }
+}
+
+impl DrawSystem for BarrelDrawSystem {
+    fn draw(
+        &mut self,
+        images: &mut crate::images::Images,
+        config: &Config,
+        context: &mut ggez::Context,
+        location: &ggez::nalgebra::Point2<f32>,
+        _physics_system: Option<crate::physics_systems::PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> ggez::GameResult {
+        self.update(config);
+        let image = &images.barrel;
+        let frame_width = image.width() as f32 / config.barrel_spritesheet_count;
+
+        ggez::graphics::draw(
+            context,
+            image,
+            DrawParam::new()
+                .src(self.spritesheet_portion)
+                .dest([
+                    location.x - config.barrel_width / 2.0,
+                    location.y - config.barrel_height / 2.0,
+                ])
+                .scale([
+                    config.barrel_width / frame_width,
+                    config.barrel_height / image.height() as f32,
+                ]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_barrel_draw_system_cycles_the_spritesheet() {
+        let mut config = crate::config::load("config.json").unwrap();
+        config.spritesheet_animation_speed = 0;
+        let mut barrel_draw_system = BarrelDrawSystem::new(&config);
+
+        barrel_draw_system.update(&config);
+        assert_eq!(
+            barrel_draw_system.spritesheet_portion.x,
+            1.0 / config.barrel_spritesheet_count
+        );
+        for _ in 1..config.barrel_spritesheet_count as u8 {
+            barrel_draw_system.update(&config);
+        }
+        assert_eq!(barrel_draw_system.spritesheet_portion.x, 0.0);
+    }
+}
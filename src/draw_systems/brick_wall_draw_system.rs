@@ -0,0 +1,92 @@
+use ggez::graphics::{self, DrawMode, DrawParam, Mesh, MeshBuilder, Rect};
+use ggez::{Context, GameResult};
+
+use crate::config::Config;
+
+use super::DrawSystem;
+
+pub struct BrickWallDrawSystem {
+    wall: Option<Mesh>,
+}
+
+impl BrickWallDrawSystem {
+    pub fn new() -> Self {
+        Self { wall: None }
+    }
+
+    /// Mortar behind rows of bricks, every other row shifted by half a brick
+    fn build_wall(context: &mut Context, config: &Config) -> GameResult<Mesh> {
+        let width = config.brick_wall_width;
+        let height = config.cave_height;
+        let brick_width = width / 2.0;
+        let mortar = 2.0;
+        let left = -width / 2.0;
+        let top = -height / 2.0;
+        let mut mesh = MeshBuilder::new();
+
+        mesh.rectangle(
+            DrawMode::fill(),
+            Rect::new(left, top, width, height),
+            config.brick_mortar_color,
+        );
+
+        let rows = (height / config.brick_height).ceil() as usize;
+        for row in 0..rows {
+            let y = top + row as f32 * config.brick_height;
+            let row_height = (height - row as f32 * config.brick_height).min(config.brick_height);
+            let shift = if row % 2 == 0 { 0.0 } else { brick_width / 2.0 };
+            let mut x = left - shift;
+            while x < -left {
+                let brick_left = x.max(left) + mortar / 2.0;
+                let brick_right = (x + brick_width).min(-left) - mortar / 2.0;
+                mesh.rectangle(
+                    DrawMode::fill(),
+                    Rect::new(
+                        brick_left,
+                        y + mortar / 2.0,
+                        brick_right - brick_left,
+                        row_height - mortar,
+                    ),
+                    config.brick_color,
+                );
+                x += brick_width;
+            }
+        }
+
+        mesh.build(context)
+    }
+}
+
+impl DrawSystem for BrickWallDrawSystem {
+    fn draw(
+        &mut self,
+        _images: &mut crate::images::Images,
+        config: &Config,
+        context: &mut Context,
+        location: &ggez::nalgebra::Point2<f32>,
+        _physics_system: Option<crate::physics_systems::PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> GameResult {
+        if self.wall.is_none() {
+            self.wall = Some(Self::build_wall(context, config)?);
+        }
+
+        if let Some(wall) = &self.wall {
+            graphics::draw(context, wall, DrawParam::new().dest(*location))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ci_test_create_brick_wall_draw_system() {
+        let brick_wall_draw_system = BrickWallDrawSystem::new();
+
+        assert!(brick_wall_draw_system.wall.is_none());
+    }
+}
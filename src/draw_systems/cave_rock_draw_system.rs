@@ -0,0 +1,51 @@
+use ggez::graphics::DrawParam;
+
+use crate::game_objects::GameObjectfeatureTypes;
+
+use super::DrawSystem;
+
+/// Stalagmites and stalactites share their meshes, built once in `Images`
+pub struct CaveRockDrawSystem {
+    rock: GameObjectfeatureTypes,
+}
+
+impl CaveRockDrawSystem {
+    pub fn new(rock: GameObjectfeatureTypes) -> Self {
+        Self { rock }
+    }
+}
+
+impl DrawSystem for CaveRockDrawSystem {
+    fn draw(
+        &mut self,
+        images: &mut crate::images::Images,
+        _config: &crate::config::Config,
+        context: &mut ggez::Context,
+        location: &ggez::nalgebra::Point2<f32>,
+        _physics_system: Option<crate::physics_systems::PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> ggez::GameResult {
+        let mesh = if self.rock == GameObjectfeatureTypes::Stalactite {
+            &images.stalactite
+        } else {
+            &images.stalagmite
+        };
+
+        ggez::graphics::draw(context, mesh, DrawParam::new().dest(*location))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ci_test_create_cave_rock_draw_system() {
+        let cave_rock_draw_system = CaveRockDrawSystem::new(GameObjectfeatureTypes::Stalactite);
+
+        assert_eq!(
+            cave_rock_draw_system.rock,
+            GameObjectfeatureTypes::Stalactite
+        );
+    }
+}
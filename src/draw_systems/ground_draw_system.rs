@@ -1,30 +1,30 @@
 use ggez::graphics::spritebatch::SpriteBatch;
-use ggez::graphics::{Color, DrawParam, Image};
+use ggez::graphics::{Color, DrawParam};
 use ggez::nalgebra::Point2;
 
 use super::DrawSystem;
 
 pub struct GroundDrawSystem {
-    grounds_spritebatch: SpriteBatch,
+    grounds: Vec<DrawParam>,
+    grounds_spritebatch: Option<SpriteBatch>,
 }
 
 impl GroundDrawSystem {
-    pub fn new(bedrock_image: Image) -> Self {
-        let grounds_spritebatch = SpriteBatch::new(bedrock_image);
-
+    pub fn new() -> Self {
         Self {
-            grounds_spritebatch,
+            grounds: vec![],
+            grounds_spritebatch: None,
         }
     }
 
     pub fn bedrock(mut self, location: Point2<f32>, color: Color) -> Self {
-        self.grounds_spritebatch
-            .add(DrawParam::new().dest([location.x, location.y]).color(color));
+        self.grounds
+            .push(DrawParam::new().dest([location.x, location.y]).color(color));
         self
     }
 
     pub fn ground(mut self, location: Point2<f32>, color: Color, scale_y: f32) -> Self {
-        self.grounds_spritebatch.add(
+        self.grounds.push(
             DrawParam::new()
                 .dest([location.x, location.y])
                 .color(color)
@@ -37,16 +37,23 @@ impl GroundDrawSystem {
 impl DrawSystem for GroundDrawSystem {
     fn draw(
         &mut self,
-        _images: &mut crate::images::Images,
+        images: &mut crate::images::Images,
         _config: &crate::config::Config,
         context: &mut ggez::Context,
         _location: &ggez::nalgebra::Point2<f32>,
         _physics_system: Option<crate::physics_systems::PhysicsState>,
         _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
     ) -> ggez::GameResult {
-        ggez::graphics::draw(context, &self.grounds_spritebatch, DrawParam::new())
-        // ggez::graphics::draw(context, &images.trees, DrawParam::new())?;
-        // ggez::graphics::draw(context, &images.foliage, DrawParam::new())
+        let grounds = &self.grounds;
+        let grounds_spritebatch = self.grounds_spritebatch.get_or_insert_with(|| {
+            let mut spritebatch = SpriteBatch::new(images.bedrock.clone());
+            grounds.iter().for_each(|ground| {
+                spritebatch.add(*ground);
+            });
+            spritebatch
+        });
+
+        ggez::graphics::draw(context, grounds_spritebatch, DrawParam::new())
     }
 }
 
@@ -54,34 +61,28 @@ impl DrawSystem for GroundDrawSystem {
 mod tests {
     use ggez::graphics::WHITE;
 
-    use crate::images::Images;
-    use crate::{config, initialize};
-
     use super::*;
 
     #[test]
-    fn test_create_new_background_draw_system() {
-        let config = config::load("config.json").unwrap();
-        let (context, _) = &mut initialize::initialize(&config).unwrap();
-        let images = Images::new(context, &config).unwrap();
-        let _background_draw_system = GroundDrawSystem::new(images.bedrock);
+    fn ci_test_create_new_background_draw_system() {
+        let background_draw_system = GroundDrawSystem::new();
+
+        assert_eq!(background_draw_system.grounds.len(), 0);
+        assert!(background_draw_system.grounds_spritebatch.is_none());
     }
 
     #[test]
-    fn test_setting_bedrock_in_background_draw_system() {
-        let config = config::load("config.json").unwrap();
-        let (context, _) = &mut initialize::initialize(&config).unwrap();
-        let images = Images::new(context, &config).unwrap();
-        let _background_draw_system =
-            GroundDrawSystem::new(images.bedrock).bedrock(Point2::new(0.3, 0.5), WHITE);
+    fn ci_test_setting_bedrock_in_background_draw_system() {
+        let background_draw_system = GroundDrawSystem::new().bedrock(Point2::new(0.3, 0.5), WHITE);
+
+        assert_eq!(background_draw_system.grounds.len(), 1);
     }
 
     #[test]
-    fn test_setting_ground_in_background_draw_system() {
-        let config = config::load("config.json").unwrap();
-        let (context, _) = &mut initialize::initialize(&config).unwrap();
-        let images = Images::new(context, &config).unwrap();
-        let _background_draw_system =
-            GroundDrawSystem::new(images.bedrock).ground(Point2::new(0.3, 0.5), WHITE, 1.5);
+    fn ci_test_setting_ground_in_background_draw_system() {
+        let background_draw_system =
+            GroundDrawSystem::new().ground(Point2::new(0.3, 0.5), WHITE, 1.5);
+
+        assert_eq!(background_draw_system.grounds.len(), 1);
     }
 }
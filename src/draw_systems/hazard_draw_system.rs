@@ -0,0 +1,139 @@
+use ggez::graphics::{DrawParam, Rect};
+
+use crate::config::Config;
+use crate::game_objects::GameObjectfeatureTypes;
+use crate::physics_systems::PhysicsState;
+
+use super::DrawSystem;
+
+/// Snakes, fires and scorpions, the sprite is bigger than the hitbox and stands on the hitbox's bottom edge
+pub struct HazardDrawSystem {
+    hazard: GameObjectfeatureTypes,
+    frames_until_sprite_change: u8,
+    spritesheet_portion: Rect,
+}
+
+struct Sprite {
+    width: f32,
+    height: f32,
+    hitbox_height: f32,
+    count: f32,
+}
+
+impl HazardDrawSystem {
+    pub fn new(config: &Config, hazard: GameObjectfeatureTypes) -> Self {
+        let sprite = Self::sprite(config, hazard);
+
+        Self {
+            hazard,
+            frames_until_sprite_change: config.spritesheet_animation_speed,
+            spritesheet_portion: Rect::new(0.0, 0.0, 1.0 / sprite.count, 1.0),
+        }
+    }
+
+    fn sprite(config: &Config, hazard: GameObjectfeatureTypes) -> Sprite {
+        match hazard {
+            GameObjectfeatureTypes::Fire => Sprite {
+                width: config.fire_width,
+                height: config.fire_height,
+                hitbox_height: config.fire_hitbox_height,
+                count: config.fire_spritesheet_count,
+            },
+            GameObjectfeatureTypes::Scorpion => Sprite {
+                width: config.scorpion_width,
+                height: config.scorpion_height,
+                hitbox_height: config.scorpion_hitbox_height,
+                count: config.scorpion_spritesheet_count,
+            },
+            _ => Sprite {
+                width: config.snake_width,
+                height: config.snake_height,
+                hitbox_height: config.snake_hitbox_height,
+                count: config.snake_spritesheet_count,
+            },
+        }
+    }
+
+    fn update(&mut self, config: &Config, count: f32) {
+        self.frames_until_sprite_change = if self.frames_until_sprite_change == 0 {
+            self.spritesheet_portion.x += 1.0 / count;
+            if self.spritesheet_portion.x >= 1.0 {
+                self.spritesheet_portion.x = 0.0;
+            }
+            config.spritesheet_animation_speed
+        } else {
+            self.frames_until_sprite_change - 1
+        };
+    }
+}
+
+impl DrawSystem for HazardDrawSystem {
+    fn draw(
+        &mut self,
+        images: &mut crate::images::Images,
+        config: &Config,
+        context: &mut ggez::Context,
+        location: &ggez::nalgebra::Point2<f32>,
+        physics_state: Option<PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> ggez::GameResult {
+        let sprite = Self::sprite(config, self.hazard);
+        self.update(config, sprite.count);
+        let image = match self.hazard {
+            GameObjectfeatureTypes::Fire => &images.fire,
+            GameObjectfeatureTypes::Scorpion => &images.scorpion,
+            _ => &images.snake,
+        };
+        let frame_width = image.width() as f32 / sprite.count;
+        let mut draw_param = DrawParam::new()
+            .src(self.spritesheet_portion)
+            .dest([
+                location.x - sprite.width / 2.0,
+                location.y + sprite.hitbox_height / 2.0 - sprite.height,
+            ])
+            .scale([
+                sprite.width / frame_width,
+                sprite.height / image.height() as f32,
+            ]);
+
+        // the sprites face left
+        if physics_state == Some(PhysicsState::MovingRight) {
+            draw_param = draw_param
+                .offset(ggez::nalgebra::Point2::new(1.0, 0.0))
+                .scale([
+                    -sprite.width / frame_width,
+                    sprite.height / image.height() as f32,
+                ]);
+        }
+
+        ggez::graphics::draw(context, image, draw_param)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_hazard_draw_system_cycles_its_own_spritesheet() {
+        let mut config = crate::config::load("config.json").unwrap();
+        config.spritesheet_animation_speed = 0;
+        let mut fire_draw_system = HazardDrawSystem::new(&config, GameObjectfeatureTypes::Fire);
+        let snake_draw_system = HazardDrawSystem::new(&config, GameObjectfeatureTypes::Snake);
+
+        assert_eq!(
+            fire_draw_system.spritesheet_portion.w,
+            1.0 / config.fire_spritesheet_count
+        );
+        assert_eq!(
+            snake_draw_system.spritesheet_portion.w,
+            1.0 / config.snake_spritesheet_count
+        );
+
+        for _ in 0..config.fire_spritesheet_count as u8 {
+            fire_draw_system.update(&config, config.fire_spritesheet_count);
+        }
+        assert_eq!(fire_draw_system.spritesheet_portion.x, 0.0);
+    }
+}
@@ -1,37 +1,44 @@
 use ggez::graphics;
-use graphics::spritebatch::{SpriteBatch, SpriteIdx};
-use graphics::{DrawParam, Image};
+use graphics::spritebatch::SpriteBatch;
+use graphics::{Color, DrawParam};
 
 use super::DrawSystem;
 
 pub struct HeartDrawSystem {
-    hearts: SpriteBatch,
+    hearts: Option<SpriteBatch>,
     lives: u8,
     x: f32,
     y: f32,
     width: f32,
-    spritebatch_indexes: Vec<SpriteIdx>,
+    heart_draw_params: Vec<DrawParam>,
+    current_lives: u8,
+    flash_frames: u8,
+    flash_frames_left: u8,
+    flashing_until: u8,
 }
 
 impl HeartDrawSystem {
-    pub fn new(heart_image: Image) -> Self {
+    pub fn new() -> Self {
         Self {
-            hearts: SpriteBatch::new(heart_image),
+            hearts: None,
             lives: 3,
             x: 0.0,
             y: 0.0,
             width: 50.0,
-            spritebatch_indexes: vec![],
+            heart_draw_params: vec![],
+            current_lives: 3,
+            flash_frames: 30,
+            flash_frames_left: 0,
+            flashing_until: 0,
         }
     }
 
     pub fn build(mut self) -> Self {
         for count in 0..self.lives {
-            let index = self
-                .hearts
-                .add(DrawParam::new().dest([self.x + count as f32 * self.width, self.y]));
-            self.spritebatch_indexes.push(index);
+            self.heart_draw_params
+                .push(DrawParam::new().dest([self.x + count as f32 * self.width, self.y]));
         }
+        self.current_lives = self.lives;
         self
     }
 
@@ -50,38 +57,87 @@ impl HeartDrawSystem {
         self.width = width;
         self
     }
+
+    pub fn set_flash_frames(mut self, flash_frames: u8) -> Self {
+        self.flash_frames = flash_frames;
+        self
+    }
+
+    /// The hearts still shown this frame, lost hearts blink and fade out before disappearing
+    fn visible_hearts(&mut self) -> Vec<DrawParam> {
+        let mut visible: Vec<DrawParam> = self
+            .heart_draw_params
+            .iter()
+            .take(self.current_lives as usize)
+            .copied()
+            .collect();
+
+        if self.flash_frames_left > 0 {
+            let flashed_for = self.flash_frames - self.flash_frames_left;
+            self.flash_frames_left -= 1;
+            if flashed_for % 6 < 3 {
+                let alpha = self.flash_frames_left as f32 / self.flash_frames.max(1) as f32;
+                visible.extend(
+                    self.heart_draw_params
+                        .iter()
+                        .take(self.flashing_until as usize)
+                        .skip(self.current_lives as usize)
+                        .map(|draw_param| draw_param.color(Color::new(1.0, 1.0, 1.0, alpha))),
+                );
+            }
+        }
+
+        visible
+    }
 }
 
 impl DrawSystem for HeartDrawSystem {
     fn draw(
         &mut self,
-        _images: &mut crate::images::Images,
+        images: &mut crate::images::Images,
         _config: &crate::config::Config,
         context: &mut ggez::Context,
         _location: &ggez::nalgebra::Point2<f32>,
         _physics_system: Option<crate::physics_systems::PhysicsState>,
-        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+        life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
     ) -> ggez::GameResult {
-        graphics::draw(context, &self.hearts, DrawParam::new())
+        if let Some(life_system) = life_system {
+            self.lives_changed(life_system.get_lives());
+        }
+
+        let visible_hearts = self.visible_hearts();
+        let hearts = self
+            .hearts
+            .get_or_insert_with(|| SpriteBatch::new(images.life.clone()));
+        hearts.clear();
+        visible_hearts.into_iter().for_each(|heart_draw_param| {
+            hearts.add(heart_draw_param);
+        });
+
+        graphics::draw(context, hearts, DrawParam::new())
+    }
+
+    fn lives_changed(&mut self, lives: u8) {
+        if lives < self.current_lives {
+            self.flashing_until = if self.flash_frames_left > 0 {
+                self.flashing_until.max(self.current_lives)
+            } else {
+                self.current_lives
+            };
+            self.flash_frames_left = self.flash_frames;
+        }
+        self.current_lives = lives;
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use ggez::graphics::Image;
-
-    use crate::config;
-    use crate::initialize::initialize;
-
     use super::*;
 
     #[test]
     #[allow(clippy::float_cmp)]
-    fn test_create_heart_draw_system() {
-        let config = &config::load("config.json").unwrap();
-        let (context, _) = &mut initialize(config).unwrap();
-        let heart_image = Image::new(context, &config.life_image).unwrap();
-        let heart_draw_system = HeartDrawSystem::new(heart_image);
+    fn ci_test_create_heart_draw_system() {
+        let heart_draw_system = HeartDrawSystem::new();
 
         assert_eq!(heart_draw_system.lives, 3);
         assert_eq!(heart_draw_system.x, 0.0);
@@ -90,21 +146,15 @@ mod tests {
     }
 
     #[test]
-    fn test_building_heart_draw_system() {
-        let config = &config::load("config.json").unwrap();
-        let (context, _) = &mut initialize(config).unwrap();
-        let heart_image = Image::new(context, &config.life_image).unwrap();
-        let heart_draw_system = HeartDrawSystem::new(heart_image).build();
-        assert_eq!(heart_draw_system.spritebatch_indexes.len(), 3);
+    fn ci_test_building_heart_draw_system() {
+        let heart_draw_system = HeartDrawSystem::new().build();
+        assert_eq!(heart_draw_system.heart_draw_params.len(), 3);
     }
 
     #[test]
     #[allow(clippy::float_cmp)]
-    fn test_create_heart_draw_system_with_overrides() {
-        let config = &config::load("config.json").unwrap();
-        let (context, _) = &mut initialize(config).unwrap();
-        let heart_image = Image::new(context, &config.life_image).unwrap();
-        let heart_draw_system = HeartDrawSystem::new(heart_image)
+    fn ci_test_create_heart_draw_system_with_overrides() {
+        let heart_draw_system = HeartDrawSystem::new()
             .set_lives(5)
             .set_location(50.0, 55.0)
             .set_width(100.0);
@@ -114,4 +164,32 @@ mod tests {
         assert_eq!(heart_draw_system.y, 55.0);
         assert_eq!(heart_draw_system.width, 100.0);
     }
+
+    #[test]
+    fn ci_test_losing_a_heart_flashes_it_away() {
+        let mut heart_draw_system = HeartDrawSystem::new().set_flash_frames(6).build();
+        assert_eq!(heart_draw_system.visible_hearts().len(), 3);
+
+        heart_draw_system.lives_changed(2);
+        let flashes: Vec<usize> = (0..7)
+            .map(|_| heart_draw_system.visible_hearts().len())
+            .collect();
+
+        assert_eq!(flashes, vec![3, 3, 3, 2, 2, 2, 2]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_lost_heart_fades_out() {
+        let mut heart_draw_system = HeartDrawSystem::new().set_flash_frames(12).build();
+
+        heart_draw_system.lives_changed(2);
+        let first = heart_draw_system.visible_hearts();
+        heart_draw_system.visible_hearts();
+        let third = heart_draw_system.visible_hearts();
+
+        assert_eq!(first[2].color.a, 11.0 / 12.0);
+        assert_eq!(third[2].color.a, 9.0 / 12.0);
+        assert_eq!(first[0].color.a, 1.0);
+    }
 }
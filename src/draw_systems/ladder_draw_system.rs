@@ -0,0 +1,94 @@
+use ggez::graphics::{self, DrawMode, DrawParam, Mesh, MeshBuilder, Rect};
+use ggez::{Context, GameResult};
+
+use crate::config::Config;
+
+use super::DrawSystem;
+
+pub struct LadderDrawSystem {
+    ladder: Option<Mesh>,
+}
+
+impl LadderDrawSystem {
+    pub fn new() -> Self {
+        Self { ladder: None }
+    }
+
+    /// Rails and rungs centered on the origin, sized to reach from a rung above the surface floor
+    /// to the cave floor
+    fn build_ladder(context: &mut Context, config: &Config) -> GameResult<Mesh> {
+        let height = config.resolution_y - config.bedrock_height - config.surface_floor_y
+            + config.ladder_rung_spacing;
+        let rail_width = config.ladder_width / 8.0;
+        let left = -config.ladder_width / 2.0;
+        let top = -height / 2.0;
+        let mut mesh = MeshBuilder::new();
+
+        mesh.rectangle(
+            DrawMode::fill(),
+            Rect::new(
+                left,
+                top + config.ladder_rung_spacing,
+                config.ladder_width,
+                config.ground_height,
+            ),
+            config.pit_color,
+        )
+        .rectangle(
+            DrawMode::fill(),
+            Rect::new(left, top, rail_width, height),
+            config.ladder_color,
+        )
+        .rectangle(
+            DrawMode::fill(),
+            Rect::new(-left - rail_width, top, rail_width, height),
+            config.ladder_color,
+        );
+
+        let mut rung_y = top + config.ladder_rung_spacing / 2.0;
+        while rung_y < -top {
+            mesh.rectangle(
+                DrawMode::fill(),
+                Rect::new(left, rung_y, config.ladder_width, rail_width),
+                config.ladder_color,
+            );
+            rung_y += config.ladder_rung_spacing;
+        }
+
+        mesh.build(context)
+    }
+}
+
+impl DrawSystem for LadderDrawSystem {
+    fn draw(
+        &mut self,
+        _images: &mut crate::images::Images,
+        config: &Config,
+        context: &mut Context,
+        location: &ggez::nalgebra::Point2<f32>,
+        _physics_system: Option<crate::physics_systems::PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> GameResult {
+        if self.ladder.is_none() {
+            self.ladder = Some(Self::build_ladder(context, config)?);
+        }
+
+        if let Some(ladder) = &self.ladder {
+            graphics::draw(context, ladder, DrawParam::new().dest(*location))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ci_test_create_ladder_draw_system() {
+        let ladder_draw_system = LadderDrawSystem::new();
+
+        assert!(ladder_draw_system.ladder.is_none());
+    }
+}
@@ -4,11 +4,21 @@ use crate::{
     config::Config, images::Images, life_systems::LifeSystem, physics_systems::PhysicsState,
 };
 
+pub mod alligator_draw_system;
+pub mod barrel_draw_system;
+pub mod brick_wall_draw_system;
+pub mod cave_rock_draw_system;
 pub mod ground_draw_system;
+pub mod hazard_draw_system;
 pub mod hearts_draw_system;
+pub mod ladder_draw_system;
 pub mod player_draw_system;
+pub mod pool_draw_system;
+pub mod relic_draw_system;
+pub mod rope_draw_system;
 pub mod single_pit_draw_system;
 pub mod tree_draw_system;
+pub mod triple_pit_draw_system;
 
 pub trait DrawSystem {
     fn draw(
@@ -20,4 +30,8 @@ pub trait DrawSystem {
         physics_system: Option<PhysicsState>,
         life_system: &Option<Box<dyn LifeSystem>>,
     ) -> GameResult;
+
+    fn lives_changed(&mut self, _lives: u8) {}
+
+    fn width_changed(&mut self, _width: f32) {}
 }
@@ -14,6 +14,9 @@ use super::DrawSystem;
 pub struct PlayerDrawSystem {
     frames_until_sprite_change: u8,
     spritesheet_portion: Rect,
+    blink_frames: u8,
+    frames_until_climbing_flip: u8,
+    climbing_flipped: bool,
 }
 
 impl PlayerDrawSystem {
@@ -26,6 +29,9 @@ impl PlayerDrawSystem {
                 1.0 / config.player_running_spritesheet_count,
                 1.0,
             ),
+            blink_frames: 0,
+            frames_until_climbing_flip: config.spritesheet_animation_speed,
+            climbing_flipped: false,
         }
     }
 
@@ -40,6 +46,16 @@ impl PlayerDrawSystem {
             self.frames_until_sprite_change - 1
         };
     }
+
+    /// Climbing has no spritesheet, we mirror the standing image back and forth instead
+    fn update_climbing(&mut self, config: &Config) {
+        self.frames_until_climbing_flip = if self.frames_until_climbing_flip == 0 {
+            self.climbing_flipped = !self.climbing_flipped;
+            config.spritesheet_animation_speed
+        } else {
+            self.frames_until_climbing_flip - 1
+        };
+    }
 }
 
 impl DrawSystem for PlayerDrawSystem {
@@ -50,8 +66,19 @@ impl DrawSystem for PlayerDrawSystem {
         context: &mut Context,
         location: &Point2<f32>,
         physics_state: Option<PhysicsState>,
-        _life_system: &Option<Box<dyn LifeSystem>>,
+        life_system: &Option<Box<dyn LifeSystem>>,
     ) -> GameResult {
+        if let Some(life_system) = life_system {
+            if life_system.is_invulnerable() {
+                self.blink_frames = self.blink_frames.wrapping_add(1);
+                if self.blink_frames / 4 % 2 == 1 {
+                    return Ok(());
+                }
+            } else {
+                self.blink_frames = 0;
+            }
+        }
+
         let mut image = &images.standing_player;
         let mut draw_param = DrawParam::new().dest([
             location.x - config.player_standing_image_width / 2.0,
@@ -74,7 +101,16 @@ impl DrawSystem for PlayerDrawSystem {
                         .offset(Point2::new(1.0, 0.0))
                         .scale([-1.0, 1.0]);
                 }
-                PhysicsState::Jumping => {}
+                PhysicsState::Climbing => {
+                    self.update_climbing(config);
+                    if self.climbing_flipped {
+                        draw_param = draw_param.offset(Point2::new(1.0, 0.0)).scale([-1.0, 1.0]);
+                    }
+                }
+                PhysicsState::Jumping
+                | PhysicsState::Swinging
+                | PhysicsState::Open
+                | PhysicsState::Closed => {}
             }
         }
 
@@ -105,6 +141,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn ci_test_player_draw_system_climbing_flips_back_and_forth() {
+        let mut config = config::load("config.json").unwrap();
+        config.spritesheet_animation_speed = 1;
+        let mut player_draw_system = PlayerDrawSystem::new(&config);
+
+        player_draw_system.update_climbing(&config);
+        assert!(!player_draw_system.climbing_flipped);
+        player_draw_system.update_climbing(&config);
+        assert!(player_draw_system.climbing_flipped);
+        player_draw_system.update_climbing(&config);
+        player_draw_system.update_climbing(&config);
+        assert!(!player_draw_system.climbing_flipped);
+    }
+
     #[test]
     fn test_player_draw_system_frames_until_sprite_change() {
         let mut config = config::load("config.json").unwrap();
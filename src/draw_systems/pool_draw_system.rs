@@ -0,0 +1,86 @@
+use ggez::graphics::{self, Color, DrawMode, DrawParam, Mesh, MeshBuilder};
+use ggez::nalgebra::Point2;
+use ggez::{Context, GameResult};
+
+use crate::config::Config;
+
+use super::DrawSystem;
+
+pub struct PoolDrawSystem {
+    color: Color,
+    width: f32,
+    pool: Option<Mesh>,
+}
+
+impl PoolDrawSystem {
+    pub fn new(color: Color, width: f32) -> Self {
+        Self {
+            color,
+            width,
+            pool: None,
+        }
+    }
+}
+
+impl DrawSystem for PoolDrawSystem {
+    fn draw(
+        &mut self,
+        _images: &mut crate::images::Images,
+        config: &Config,
+        context: &mut Context,
+        location: &Point2<f32>,
+        _physics_system: Option<crate::physics_systems::PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> GameResult {
+        // a fully closed pool has nothing to draw
+        if self.width < 1.0 {
+            return Ok(());
+        }
+
+        // one unit sized pool, stretched to the current width every draw instead of rebuilt
+        if self.pool.is_none() {
+            self.pool = Some(
+                MeshBuilder::new()
+                    .circle(
+                        DrawMode::fill(),
+                        Point2::new(0.0, 0.0),
+                        0.5,
+                        1.0 / config.pool_max_width.max(config.pit_height),
+                        self.color,
+                    )
+                    .build(context)?,
+            );
+        }
+
+        if let Some(pool) = &self.pool {
+            graphics::draw(
+                context,
+                pool,
+                DrawParam::new()
+                    .dest(*location)
+                    .scale([self.width, config.pit_height]),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn width_changed(&mut self, width: f32) {
+        self.width = width;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_pool_draw_system_tracks_the_width() {
+        let mut pool_draw_system = PoolDrawSystem::new(graphics::BLACK, 0.0);
+
+        pool_draw_system.width_changed(120.0);
+
+        assert_eq!(pool_draw_system.width, 120.0);
+    }
+}
@@ -0,0 +1,52 @@
+use ggez::graphics::DrawParam;
+
+use super::DrawSystem;
+
+pub struct RelicDrawSystem {
+    relic_index: usize,
+}
+
+impl RelicDrawSystem {
+    pub fn new(relic_index: usize) -> Self {
+        Self { relic_index }
+    }
+}
+
+impl DrawSystem for RelicDrawSystem {
+    fn draw(
+        &mut self,
+        images: &mut crate::images::Images,
+        config: &crate::config::Config,
+        context: &mut ggez::Context,
+        location: &ggez::nalgebra::Point2<f32>,
+        _physics_system: Option<crate::physics_systems::PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> ggez::GameResult {
+        let image = &images.relics[self.relic_index];
+        ggez::graphics::draw(
+            context,
+            image,
+            DrawParam::new()
+                .dest([
+                    location.x - config.relic_width / 2.0,
+                    location.y - config.relic_height / 2.0,
+                ])
+                .scale([
+                    config.relic_width / image.width() as f32,
+                    config.relic_height / image.height() as f32,
+                ]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ci_test_create_relic_draw_system() {
+        let relic_draw_system = RelicDrawSystem::new(2);
+
+        assert_eq!(relic_draw_system.relic_index, 2);
+    }
+}
@@ -0,0 +1,77 @@
+use ggez::graphics::{self, DrawParam, Mesh};
+use ggez::nalgebra::Point2;
+
+use super::DrawSystem;
+
+pub struct RopeDrawSystem {
+    rope: Option<Mesh>,
+}
+
+impl RopeDrawSystem {
+    pub fn new() -> Self {
+        Self { rope: None }
+    }
+
+    /// The rope mesh hangs straight down, this is how far to turn it so it reaches the end of the rope
+    fn swing_rotation(anchor: &Point2<f32>, end: &Point2<f32>) -> f32 {
+        -(end.x - anchor.x).atan2(end.y - anchor.y)
+    }
+}
+
+impl DrawSystem for RopeDrawSystem {
+    fn draw(
+        &mut self,
+        _images: &mut crate::images::Images,
+        config: &crate::config::Config,
+        context: &mut ggez::Context,
+        location: &Point2<f32>,
+        _physics_system: Option<crate::physics_systems::PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> ggez::GameResult {
+        if self.rope.is_none() {
+            self.rope = Some(Mesh::new_line(
+                context,
+                &[Point2::new(0.0, 0.0), Point2::new(0.0, config.rope_length)],
+                config.rope_width,
+                config.rope_color,
+            )?);
+        }
+
+        let anchor = Point2::new(config.resolution_x / 2.0, config.rope_anchor_y);
+
+        if let Some(rope) = &self.rope {
+            graphics::draw(
+                context,
+                rope,
+                DrawParam::new()
+                    .dest(anchor)
+                    .rotation(Self::swing_rotation(&anchor, location)),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ci_test_create_rope_draw_system() {
+        let rope_draw_system: RopeDrawSystem = RopeDrawSystem::new();
+
+        assert!(rope_draw_system.rope.is_none());
+    }
+
+    #[test]
+    fn ci_test_rope_turns_towards_the_end_of_the_rope() {
+        let anchor = Point2::new(100.0, 0.0);
+        let turn = |end: Point2<f32>| RopeDrawSystem::swing_rotation(&anchor, &end);
+
+        assert!(turn(Point2::new(100.0, 50.0)).abs() < f32::EPSILON);
+        // turning clockwise on screen swings the bottom of the rope to the left
+        assert!(turn(Point2::new(50.0, 50.0)) > 0.0);
+        assert!(turn(Point2::new(150.0, 50.0)) < 0.0);
+    }
+}
@@ -0,0 +1,45 @@
+use ggez::graphics::DrawParam;
+
+use crate::game_objects::builders::pit3::pit3_offsets;
+
+use super::DrawSystem;
+
+pub struct TriplePitDrawSystem;
+
+impl TriplePitDrawSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DrawSystem for TriplePitDrawSystem {
+    fn draw(
+        &mut self,
+        images: &mut crate::images::Images,
+        config: &crate::config::Config,
+        context: &mut ggez::Context,
+        location: &ggez::nalgebra::Point2<f32>,
+        _physics_system: Option<crate::physics_systems::PhysicsState>,
+        _life_system: &Option<Box<dyn crate::life_systems::LifeSystem>>,
+    ) -> ggez::GameResult {
+        pit3_offsets(config.pit_width, config.pit3_spacing)
+            .iter()
+            .try_for_each(|offset| {
+                ggez::graphics::draw(
+                    context,
+                    &images.pit1,
+                    DrawParam::new().dest([location.x + offset, location.y]),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ci_test_create_triple_pit_draw_system() {
+        let _pit_draw_system: TriplePitDrawSystem = TriplePitDrawSystem::new();
+    }
+}
@@ -0,0 +1,63 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::draw_systems::alligator_draw_system::AlligatorDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+use crate::physics_systems::alligator_physics_system::AlligatorPhysicsSystem;
+
+/// The heads are spread evenly around the middle of the screen and stick up out of the water
+pub fn create_alligators(config: &Config) -> Result<Vec<GameObject>, GameObjectBuilderError> {
+    let row_width = config.alligator_spacing * config.alligator_count.saturating_sub(1) as f32;
+
+    (0..config.alligator_count)
+        .map(|index| {
+            GameObjectBuilder::new()
+                .with_type(GameObjectTypes::Feature)
+                .location(Point2::new(
+                    config.resolution_x / 2.0 - row_width / 2.0
+                        + config.alligator_spacing * index as f32,
+                    config.surface_floor_y - config.alligator_height / 2.0,
+                ))
+                .width(config.alligator_width)
+                .height(config.alligator_height)
+                .draw_system(Box::new(AlligatorDrawSystem::new()))
+                .physics_system(Box::new(AlligatorPhysicsSystem::new(config, index)))
+                .with_feature_type(GameObjectfeatureTypes::Alligator)
+                .build()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+    use crate::physics_systems::PhysicsState;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_create_alligators() {
+        let config = &config::load("config.json").unwrap();
+        let alligators = create_alligators(config).unwrap();
+
+        assert_eq!(alligators.len(), config.alligator_count);
+        assert_eq!(
+            alligators[1].location.x - alligators[0].location.x,
+            config.alligator_spacing
+        );
+        let middle = (alligators[0].location.x + alligators[alligators.len() - 1].location.x) / 2.0;
+        assert_eq!(middle, config.resolution_x / 2.0);
+        assert_eq!(
+            alligators[0].location.y - alligators[0].height / 2.0,
+            config.surface_floor_y - config.alligator_height
+        );
+        assert_eq!(alligators[0].physics_state, Some(PhysicsState::Closed));
+        assert_eq!(
+            alligators[alligators.len() - 1].physics_state,
+            Some(PhysicsState::Open)
+        );
+    }
+}
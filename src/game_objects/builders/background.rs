@@ -1,4 +1,3 @@
-use ggez::graphics::Image;
 use ggez::nalgebra::Point2;
 
 use crate::config::Config;
@@ -6,17 +5,13 @@ use crate::draw_systems::ground_draw_system::GroundDrawSystem;
 use crate::draw_systems::tree_draw_system::TreeDrawSystem;
 use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
 use crate::game_objects::{GameObject, GameObjectTypes};
-use crate::images::Images;
 
-pub fn create_behind_ground(
-    images: &Images,
-    config: &Config,
-) -> Result<GameObject, GameObjectBuilderError> {
+pub fn create_behind_ground(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
     GameObjectBuilder::new()
         .location(Point2::new(0.0, 0.0))
         .width(config.resolution_x)
         .draw_system(Box::new(
-            GroundDrawSystem::new(images.bedrock.clone())
+            GroundDrawSystem::new()
                 .bedrock(
                     Point2::new(0.0, config.resolution_y - config.bedrock_height),
                     config.bedrock_color,
@@ -50,11 +45,8 @@ pub fn create_behind_ground(
         .build()
 }
 
-pub fn create_above_ground(
-    bedrock_image: Image,
-    config: &Config,
-) -> Result<GameObject, GameObjectBuilderError> {
-    let draw_system = GroundDrawSystem::new(bedrock_image)
+pub fn create_above_ground(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    let draw_system = GroundDrawSystem::new()
         .ground(
             Point2::new(
                 0.0,
@@ -105,16 +97,12 @@ pub fn create_trees() -> Result<GameObject, GameObjectBuilderError> {
 mod tests {
     use crate::config;
     use crate::game_objects::{GameObject, GameObjectTypes};
-    use crate::images::Images;
-    use crate::initialize::initialize;
 
     #[test]
     #[allow(clippy::float_cmp)]
-    fn test_create_background() {
+    fn ci_test_create_background() {
         let config = &config::load("config.json").unwrap();
-        let (context, _) = &mut initialize(config).unwrap();
-        let images = &Images::new(context, config).unwrap();
-        let background: GameObject = super::create_behind_ground(images, config).unwrap();
+        let background: GameObject = super::create_behind_ground(config).unwrap();
 
         assert_eq!(background.location.x, 0.0);
         assert_eq!(background.location.y, 0.0);
@@ -123,12 +111,9 @@ mod tests {
 
     #[test]
     #[allow(clippy::float_cmp)]
-    fn test_create_above_background() {
+    fn ci_test_create_above_background() {
         let config = &config::load("config.json").unwrap();
-        let (context, _) = &mut initialize(config).unwrap();
-        let images = &Images::new(context, config).unwrap();
-        let background: GameObject =
-            super::create_above_ground(images.bedrock.clone(), config).unwrap();
+        let background: GameObject = super::create_above_ground(config).unwrap();
 
         assert_eq!(background.feature_type, None);
         assert_eq!(
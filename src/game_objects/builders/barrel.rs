@@ -0,0 +1,56 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::draw_systems::barrel_draw_system::BarrelDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+use crate::physics_systems::barrel_physics_system::BarrelPhysicsSystem;
+
+/// Barrels start near the far edge they roll away from, `barrel_spacing` apart
+pub fn create_barrel(config: &Config, index: usize) -> Result<GameObject, GameObjectBuilderError> {
+    let offset = config.barrel_spacing * index as f32;
+    let x = if config.barrel_velocity < 0.0 {
+        config.resolution_x * 0.9 + offset
+    } else {
+        config.resolution_x * 0.1 - offset
+    };
+
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            x,
+            config.surface_floor_y - config.barrel_height / 2.0,
+        ))
+        .width(config.barrel_width)
+        .height(config.barrel_height)
+        .draw_system(Box::new(BarrelDrawSystem::new(config)))
+        .physics_system(Box::new(BarrelPhysicsSystem::new(config)))
+        .with_feature_type(GameObjectfeatureTypes::Barrel)
+        .build()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_create_barrels() {
+        let config = &config::load("config.json").unwrap();
+        let first: GameObject = create_barrel(config, 0).unwrap();
+        let second: GameObject = create_barrel(config, 1).unwrap();
+
+        assert_eq!(
+            first.location.y + first.height / 2.0,
+            config.surface_floor_y
+        );
+        assert_eq!(
+            (second.location.x - first.location.x).abs(),
+            config.barrel_spacing
+        );
+        assert_eq!(first.feature_type, Some(GameObjectfeatureTypes::Barrel));
+    }
+}
@@ -0,0 +1,57 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::{Config, WallSide};
+use crate::draw_systems::brick_wall_draw_system::BrickWallDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+
+/// Brick walls fill the cave from its ceiling to its floor near one edge of the screen
+pub fn create_brick_wall(
+    config: &Config,
+    side: WallSide,
+) -> Result<GameObject, GameObjectBuilderError> {
+    let x = match side {
+        WallSide::Left => config.resolution_x * 0.1,
+        WallSide::Right => config.resolution_x * 0.9,
+    };
+
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            x,
+            config.resolution_y - config.bedrock_height - config.cave_height / 2.0,
+        ))
+        .width(config.brick_wall_width)
+        .height(config.cave_height)
+        .draw_system(Box::new(BrickWallDrawSystem::new()))
+        .with_feature_type(GameObjectfeatureTypes::BrickWall)
+        .build()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_create_brick_wall() {
+        let config = &config::load("config.json").unwrap();
+        let left_wall: GameObject = create_brick_wall(config, WallSide::Left).unwrap();
+        let right_wall: GameObject = create_brick_wall(config, WallSide::Right).unwrap();
+
+        assert!(left_wall.location.x < config.resolution_x / 2.0);
+        assert!(right_wall.location.x > config.resolution_x / 2.0);
+        assert_eq!(
+            left_wall.location.y + left_wall.height / 2.0,
+            config.resolution_y - config.bedrock_height
+        );
+        assert_eq!(left_wall.width, config.brick_wall_width);
+        assert_eq!(
+            left_wall.feature_type,
+            Some(GameObjectfeatureTypes::BrickWall)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::draw_systems::cave_rock_draw_system::CaveRockDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+
+/// Stalagmites grow up from the cave floor
+pub fn create_stalagmite(config: &Config, x: f32) -> Result<GameObject, GameObjectBuilderError> {
+    let cave_floor_y = config.resolution_y - config.bedrock_height;
+
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            x,
+            cave_floor_y - config.stalagmite_height / 2.0,
+        ))
+        .width(config.stalagmite_width)
+        .height(config.stalagmite_height)
+        .draw_system(Box::new(CaveRockDrawSystem::new(
+            GameObjectfeatureTypes::Stalagmite,
+        )))
+        .with_feature_type(GameObjectfeatureTypes::Stalagmite)
+        .build()
+}
+
+/// Stalactites hang from the cave ceiling
+pub fn create_stalactite(config: &Config, x: f32) -> Result<GameObject, GameObjectBuilderError> {
+    let cave_ceiling_y = config.resolution_y - config.bedrock_height - config.cave_height;
+
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            x,
+            cave_ceiling_y + config.stalactite_height / 2.0,
+        ))
+        .width(config.stalactite_width)
+        .height(config.stalactite_height)
+        .draw_system(Box::new(CaveRockDrawSystem::new(
+            GameObjectfeatureTypes::Stalactite,
+        )))
+        .with_feature_type(GameObjectfeatureTypes::Stalactite)
+        .build()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_cave_rocks_touch_the_floor_and_ceiling() {
+        let config = &config::load("config.json").unwrap();
+        let stalagmite = create_stalagmite(config, 700.0).unwrap();
+        let stalactite = create_stalactite(config, 1300.0).unwrap();
+
+        assert_eq!(stalagmite.location.x, 700.0);
+        assert_eq!(
+            stalagmite.location.y + stalagmite.height / 2.0,
+            config.resolution_y - config.bedrock_height
+        );
+        assert_eq!(
+            stalactite.location.y - stalactite.height / 2.0,
+            config.resolution_y - config.bedrock_height - config.cave_height
+        );
+        assert_eq!(
+            stalactite.feature_type,
+            Some(GameObjectfeatureTypes::Stalactite)
+        );
+    }
+}
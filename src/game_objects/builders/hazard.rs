@@ -0,0 +1,112 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::draw_systems::hazard_draw_system::HazardDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+use crate::physics_systems::scorpion_physics_system::ScorpionPhysicsSystem;
+
+pub fn create_snake(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    create_hazard(
+        config,
+        GameObjectfeatureTypes::Snake,
+        config.snake_hitbox_width,
+        config.snake_hitbox_height,
+    )
+}
+
+pub fn create_fire(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    create_hazard(
+        config,
+        GameObjectfeatureTypes::Fire,
+        config.fire_hitbox_width,
+        config.fire_hitbox_height,
+    )
+}
+
+/// Scorpions start on the cave floor under where the surface hazards sit
+pub fn create_scorpion(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    let cave_floor_y = config.resolution_y - config.bedrock_height;
+
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            config.hazard_x,
+            cave_floor_y - config.scorpion_hitbox_height / 2.0,
+        ))
+        .width(config.scorpion_hitbox_width)
+        .height(config.scorpion_hitbox_height)
+        .draw_system(Box::new(HazardDrawSystem::new(
+            config,
+            GameObjectfeatureTypes::Scorpion,
+        )))
+        .physics_system(Box::new(ScorpionPhysicsSystem::new(config)))
+        .with_feature_type(GameObjectfeatureTypes::Scorpion)
+        .build()
+}
+
+/// The game object is only the hitbox, sitting on the surface floor
+fn create_hazard(
+    config: &Config,
+    hazard: GameObjectfeatureTypes,
+    hitbox_width: f32,
+    hitbox_height: f32,
+) -> Result<GameObject, GameObjectBuilderError> {
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            config.hazard_x,
+            config.surface_floor_y - hitbox_height / 2.0,
+        ))
+        .width(hitbox_width)
+        .height(hitbox_height)
+        .draw_system(Box::new(HazardDrawSystem::new(config, hazard)))
+        .with_feature_type(hazard)
+        .build()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+    use crate::life_systems::DeathCause;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_hazard_hitboxes_are_smaller_than_their_sprites() {
+        let config = &config::load("config.json").unwrap();
+        let snake = create_snake(config).unwrap();
+        let fire = create_fire(config).unwrap();
+
+        assert!(snake.width < config.snake_width && snake.height < config.snake_height);
+        assert!(fire.width < config.fire_width && fire.height < config.fire_height);
+        assert_eq!(
+            snake.location.y + snake.height / 2.0,
+            config.surface_floor_y
+        );
+        assert_eq!(
+            snake.feature_type.unwrap().hazard(),
+            Some(DeathCause::Snake)
+        );
+        assert_eq!(fire.feature_type.unwrap().hazard(), Some(DeathCause::Fire));
+    }
+
+    #[test]
+    fn ci_test_scorpions_walk_the_cave_floor() {
+        let config = &config::load("config.json").unwrap();
+        let scorpion = create_scorpion(config).unwrap();
+
+        assert!(scorpion.width < config.scorpion_width);
+        assert_eq!(
+            scorpion.location.y + scorpion.height / 2.0,
+            config.resolution_y - config.bedrock_height
+        );
+        assert_eq!(
+            scorpion.feature_type.unwrap().hazard(),
+            Some(DeathCause::Scorpion)
+        );
+        assert!(scorpion.physics_state.is_some());
+    }
+}
@@ -4,22 +4,18 @@ use crate::config::Config;
 use crate::draw_systems::hearts_draw_system::HeartDrawSystem;
 use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
 use crate::game_objects::GameObject;
-use crate::images::Images;
 
-pub fn create_hearts(
-    images: &Images,
-    config: &Config,
-) -> Result<GameObject, GameObjectBuilderError> {
-    let life_image_width = images.life.width() as f32;
+pub fn create_hearts(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
     GameObjectBuilder::new()
         .draw_system(Box::new(
-            HeartDrawSystem::new(images.life.clone())
+            HeartDrawSystem::new()
                 .set_lives(config.player_lives)
                 .set_location(
-                    config.resolution_x - life_image_width * config.player_lives as f32,
+                    config.resolution_x - config.life_width * config.player_lives as f32,
                     0.0,
                 )
-                .set_width(life_image_width)
+                .set_width(config.life_width)
+                .set_flash_frames(config.heart_flash_frames)
                 .build(),
         ))
         .location(Point2::new(config.resolution_x - config.life_width, 0.0))
@@ -32,16 +28,12 @@ pub fn create_hearts(
 mod tests {
     use crate::config;
     use crate::game_objects::{GameObject, GameObjectTypes};
-    use crate::images::Images;
-    use crate::initialize::initialize;
 
     #[test]
     #[allow(clippy::float_cmp)]
-    fn test_create_hearts_game_object() {
+    fn ci_test_create_hearts_game_object() {
         let config = &config::load("config.json").unwrap();
-        let (context, _) = &mut initialize(config).unwrap();
-        let images = &Images::new(context, config).unwrap();
-        let hearts: GameObject = super::create_hearts(images, config).unwrap();
+        let hearts: GameObject = super::create_hearts(config).unwrap();
 
         assert_eq!(hearts.location.x, config.resolution_x - config.life_width);
         assert_eq!(hearts.location.y, 0.0);
@@ -0,0 +1,52 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::draw_systems::ladder_draw_system::LadderDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+
+/// The ladder reaches from the cave floor up through the surface floor, poking out a rung
+/// so someone standing over the hole is touching it
+pub fn create_ladder(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    let cave_floor_y = config.resolution_y - config.bedrock_height;
+    let top_y = config.surface_floor_y - config.ladder_rung_spacing;
+
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            config.resolution_x / 2.0,
+            (top_y + cave_floor_y) / 2.0,
+        ))
+        .width(config.ladder_width)
+        .height(cave_floor_y - top_y)
+        .draw_system(Box::new(LadderDrawSystem::new()))
+        .with_feature_type(GameObjectfeatureTypes::Ladder)
+        .build()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_create_ladder() {
+        let config = &config::load("config.json").unwrap();
+        let ladder: GameObject = create_ladder(config).unwrap();
+
+        assert_eq!(ladder.location.x, config.resolution_x / 2.0);
+        assert_eq!(
+            ladder.location.y - ladder.height / 2.0,
+            config.surface_floor_y - config.ladder_rung_spacing
+        );
+        assert_eq!(
+            ladder.location.y + ladder.height / 2.0,
+            config.resolution_y - config.bedrock_height
+        );
+        assert_eq!(ladder.width, config.ladder_width);
+        assert_eq!(ladder.feature_type, Some(GameObjectfeatureTypes::Ladder));
+    }
+}
@@ -1,4 +1,14 @@
+pub mod alligator;
 pub mod background;
+pub mod barrel;
+pub mod brick_wall;
+pub mod cave_rock;
+pub mod hazard;
 pub mod hearts;
+pub mod ladder;
 pub mod pit1;
+pub mod pit3;
 pub mod player;
+pub mod pool;
+pub mod relic;
+pub mod rope;
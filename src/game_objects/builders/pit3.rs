@@ -0,0 +1,58 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::draw_systems::triple_pit_draw_system::TriplePitDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+
+pub fn create_pit3(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            config.resolution_x / 2.0,
+            config.resolution_y
+                - config.bedrock_height
+                - config.cave_height
+                - config.ground_height
+                - (config.surface_bottom_height + config.surface_top_height) / 2.0,
+        ))
+        .width(config.pit_width * 3.0 + config.pit3_spacing * 2.0)
+        .height(config.pit_height)
+        .draw_system(Box::new(TriplePitDrawSystem::new()))
+        .with_feature_type(GameObjectfeatureTypes::Pit3)
+        .build()
+}
+
+/// Horizontal offsets of each pit's center from the center of the whole layout
+pub fn pit3_offsets(pit_width: f32, spacing: f32) -> [f32; 3] {
+    [-(pit_width + spacing), 0.0, pit_width + spacing]
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_create_pit3() {
+        let config = &config::load("config.json").unwrap();
+        let pit3: GameObject = create_pit3(config).unwrap();
+
+        assert_eq!(pit3.location.x, config.resolution_x / 2.0);
+        assert_eq!(
+            pit3.width,
+            config.pit_width * 3.0 + config.pit3_spacing * 2.0
+        );
+        assert_eq!(pit3.height, config.pit_height);
+        assert_eq!(pit3.my_type, GameObjectTypes::Feature);
+        assert_eq!(pit3.feature_type, Some(GameObjectfeatureTypes::Pit3));
+    }
+
+    #[test]
+    fn ci_test_pit3_offsets() {
+        assert_eq!(pit3_offsets(100.0, 50.0), [-150.0, 0.0, 150.0]);
+    }
+}
@@ -14,8 +14,16 @@ pub fn create_player(config: &Config) -> Result<GameObject, GameObjectBuilderErr
             config.player_starting_y,
         ))
         .width(config.player_width)
+        .height(config.player_height)
         .draw_system(Box::new(PlayerDrawSystem::new(config)))
-        .life_system(Box::new(PlayerLifeSystem::new(config.player_lives)))
+        .life_system(Box::new(
+            PlayerLifeSystem::new(config.player_lives)
+                .set_respawn_location(Point2::new(
+                    config.player_starting_x,
+                    config.player_starting_y,
+                ))
+                .set_invulnerable_ticks(config.player_invulnerable_ticks),
+        ))
         .physics_system(Box::new(PlayerPhysicsSystem::new(config)))
         .with_type(GameObjectTypes::Player)
         .build()
@@ -39,5 +47,6 @@ mod tests {
         assert_eq!(player.location.y, config.player_starting_y);
         assert_eq!(player.my_type, GameObjectTypes::Player);
         assert_eq!(player.width, config.player_width);
+        assert_eq!(player.height, config.player_height);
     }
 }
@@ -0,0 +1,103 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::draw_systems::pool_draw_system::PoolDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+use crate::physics_systems::pool_physics_system::PoolPhysicsSystem;
+
+pub fn create_tar_pit(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    create_pool(config, GameObjectfeatureTypes::TarPit)
+}
+
+pub fn create_lake(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    create_pool(config, GameObjectfeatureTypes::Lake)
+}
+
+/// Alligators need the water to stay put, so this lake is always fully open
+pub fn create_still_lake(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(pool_location(config))
+        .width(config.pool_max_width)
+        .height(config.pit_height)
+        .draw_system(Box::new(PoolDrawSystem::new(
+            config.lake_color,
+            config.pool_max_width,
+        )))
+        .with_feature_type(GameObjectfeatureTypes::Lake)
+        .build()
+}
+
+/// Tar pits and lakes sit where the single pit does
+fn pool_location(config: &Config) -> Point2<f32> {
+    Point2::new(
+        config.resolution_x / 2.0,
+        config.resolution_y
+            - config.bedrock_height
+            - config.cave_height
+            - config.ground_height
+            - (config.surface_bottom_height + config.surface_top_height) / 2.0,
+    )
+}
+
+/// Starts closed and opens with the pool cycle
+fn create_pool(
+    config: &Config,
+    feature_type: GameObjectfeatureTypes,
+) -> Result<GameObject, GameObjectBuilderError> {
+    let color = if feature_type == GameObjectfeatureTypes::Lake {
+        config.lake_color
+    } else {
+        config.tar_pit_color
+    };
+
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(pool_location(config))
+        .width(config.pool_min_width)
+        .height(config.pit_height)
+        .draw_system(Box::new(PoolDrawSystem::new(color, config.pool_min_width)))
+        .physics_system(Box::new(PoolPhysicsSystem::new(config)))
+        .with_feature_type(feature_type)
+        .build()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use std::slice;
+
+    use crate::config;
+    use crate::handle_input::InputState;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_pools_grow_with_their_cycle() {
+        let config = &config::load("config.json").unwrap();
+        let mut tar_pit: GameObject = create_tar_pit(config).unwrap();
+        let lake: GameObject = create_lake(config).unwrap();
+
+        assert_eq!(tar_pit.width, config.pool_min_width);
+        assert_eq!(tar_pit.feature_type, Some(GameObjectfeatureTypes::TarPit));
+        assert_eq!(lake.feature_type, Some(GameObjectfeatureTypes::Lake));
+
+        for _ in 0..config.pool_cycle_ticks / 2 {
+            GameObject::update_in_place(slice::from_mut(&mut tar_pit), 0, &InputState::new());
+        }
+        assert!(tar_pit.width > config.pool_max_width - 1.0);
+    }
+
+    #[test]
+    fn ci_test_still_lake_stays_open() {
+        let config = &config::load("config.json").unwrap();
+        let mut lake: GameObject = create_still_lake(config).unwrap();
+
+        GameObject::update_in_place(slice::from_mut(&mut lake), 0, &InputState::new());
+
+        assert_eq!(lake.width, config.pool_max_width);
+        assert_eq!(lake.feature_type, Some(GameObjectfeatureTypes::Lake));
+    }
+}
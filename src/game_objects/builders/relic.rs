@@ -0,0 +1,47 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::draw_systems::relic_draw_system::RelicDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+
+pub fn create_relic(
+    config: &Config,
+    relic_index: usize,
+) -> Result<GameObject, GameObjectBuilderError> {
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(Point2::new(
+            config.resolution_x * 0.75,
+            config.surface_floor_y - config.relic_height / 2.0,
+        ))
+        .width(config.relic_width)
+        .height(config.relic_height)
+        .draw_system(Box::new(RelicDrawSystem::new(relic_index)))
+        .with_feature_type(GameObjectfeatureTypes::Relic(relic_index))
+        .build()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_create_relic() {
+        let config = &config::load("config.json").unwrap();
+        let relic: GameObject = create_relic(config, 1).unwrap();
+
+        assert_eq!(relic.location.x, config.resolution_x * 0.75);
+        assert_eq!(
+            relic.location.y + relic.height / 2.0,
+            config.surface_floor_y
+        );
+        assert_eq!(relic.my_type, GameObjectTypes::Feature);
+        assert_eq!(relic.width, config.relic_width);
+        assert_eq!(relic.feature_type, Some(GameObjectfeatureTypes::Relic(1)));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::config::Config;
+use crate::draw_systems::rope_draw_system::RopeDrawSystem;
+use crate::game_objects::game_object::{GameObjectBuilder, GameObjectBuilderError};
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
+use crate::game_objects::{GameObject, GameObjectTypes};
+use crate::physics_systems::rope_physics_system::RopePhysicsSystem;
+
+pub fn create_rope(config: &Config) -> Result<GameObject, GameObjectBuilderError> {
+    let rope_physics_system = RopePhysicsSystem::new(config);
+
+    GameObjectBuilder::new()
+        .with_type(GameObjectTypes::Feature)
+        .location(rope_physics_system.end_of_rope())
+        .width(config.rope_grab_size)
+        .height(config.rope_grab_size)
+        .draw_system(Box::new(RopeDrawSystem::new()))
+        .physics_system(Box::new(rope_physics_system))
+        .with_feature_type(GameObjectfeatureTypes::Rope)
+        .build()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_create_rope() {
+        let config = &config::load("config.json").unwrap();
+        let rope: GameObject = create_rope(config).unwrap();
+
+        assert_eq!(
+            rope.location.x,
+            config.resolution_x / 2.0 + config.rope_length * config.rope_max_angle.sin()
+        );
+        assert_eq!(rope.width, config.rope_grab_size);
+        assert_eq!(rope.my_type, GameObjectTypes::Feature);
+        assert_eq!(rope.feature_type, Some(GameObjectfeatureTypes::Rope));
+    }
+}
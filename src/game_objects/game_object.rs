@@ -1,12 +1,13 @@
 use ggez::nalgebra::Point2;
 use ggez::{Context, GameResult};
 
+use crate::collisions::{Collision, CollisionLayer};
 use crate::config::Config;
 use crate::draw_systems::DrawSystem;
-use crate::handle_input::Command;
+use crate::handle_input::InputState;
 use crate::images::Images;
-use crate::life_systems::LifeSystem;
-use crate::physics_systems::PhysicsSystem;
+use crate::life_systems::{DeathCause, LifeSystem};
+use crate::physics_systems::{PhysicsState, PhysicsSystem};
 
 use super::game_object_types::GameObjectfeatureTypes;
 use super::GameObjectTypes;
@@ -20,6 +21,8 @@ pub struct GameObject {
     physics_system: Option<Box<dyn PhysicsSystem>>,
     pub my_type: GameObjectTypes,
     pub feature_type: Option<GameObjectfeatureTypes>,
+    /// Last state the physics system reported, kept so other objects can see it while ours is busy
+    pub physics_state: Option<PhysicsState>,
 }
 
 impl GameObject {
@@ -29,11 +32,10 @@ impl GameObject {
         config: &Config,
         images: &mut Images,
     ) -> GameResult {
-        let physics_state = if let Some(physics_system) = &self.physics_system {
-            Some(physics_system.get_state())
-        } else {
-            None
-        };
+        let physics_state = self
+            .physics_system
+            .as_ref()
+            .map(|physics_system| physics_system.get_state());
 
         if let Some(draw_system) = &mut self.draw_system {
             draw_system.draw(
@@ -49,9 +51,102 @@ impl GameObject {
         Ok(())
     }
 
-    pub fn update(&mut self, command: Option<Command>, features: Vec<GameObject>) {
+    /// Updates the object at index with all of objects as its world, borrowing instead of copying it.
+    /// Its own physics system is out of the slice while it runs so it sees itself without one
+    pub fn update_in_place(objects: &mut [GameObject], index: usize, input: &InputState) {
+        objects[index].update_life();
+
+        if let Some(mut physics_system) = objects[index].physics_system.take() {
+            let mut location = objects[index].location;
+            physics_system.update(&mut location, input, objects);
+            objects[index].location = location;
+            objects[index].physics_updated(physics_system);
+        }
+    }
+
+    fn update_life(&mut self) {
+        if let Some(life_system) = &mut self.life_system {
+            life_system.update();
+        }
+    }
+
+    fn physics_updated(&mut self, physics_system: Box<dyn PhysicsSystem>) {
+        self.physics_state = Some(physics_system.get_state());
+
+        if let Some(width) = physics_system.get_width() {
+            self.width = width;
+            if let Some(draw_system) = &mut self.draw_system {
+                draw_system.width_changed(width);
+            }
+        }
+
+        self.physics_system = Some(physics_system);
+        self.apply_hurt();
+    }
+
+    pub fn collided(&mut self, collision: &Collision) {
         if let Some(physics_system) = &mut self.physics_system {
-            physics_system.update(&mut self.location, command, features);
+            physics_system.collided(&mut self.location, collision);
+        }
+
+        self.apply_hurt();
+    }
+
+    fn apply_hurt(&mut self) {
+        let physics_system = match &mut self.physics_system {
+            Some(physics_system) => physics_system,
+            None => return,
+        };
+
+        if let Some(cause) = physics_system.take_hurt() {
+            // a fall always puts us back at the respawn point, invulnerability shrugs off anything else
+            if let Some(life_system) = &mut self.life_system {
+                if life_system.lose_life(cause) || cause.is_fall() {
+                    self.location = life_system.respawn_location();
+                    physics_system.respawn();
+                }
+            }
+        }
+    }
+
+    pub fn take_relics(&mut self) -> Vec<usize> {
+        self.physics_system
+            .as_mut()
+            .map_or_else(Vec::new, |physics_system| physics_system.take_relics())
+    }
+
+    pub fn take_knockback(&mut self) -> bool {
+        self.physics_system
+            .as_mut()
+            .is_some_and(|physics_system| physics_system.take_knockback())
+    }
+
+    pub fn get_lives(&self) -> Option<u8> {
+        self.life_system
+            .as_ref()
+            .map(|life_system| life_system.get_lives())
+    }
+
+    pub fn lives_changed(&mut self, lives: u8) {
+        if let Some(draw_system) = &mut self.draw_system {
+            draw_system.lives_changed(lives);
+        }
+    }
+
+    pub fn get_death_cause(&self) -> Option<DeathCause> {
+        self.life_system
+            .as_ref()
+            .and_then(|life_system| life_system.get_death_cause())
+    }
+
+    /// The player is solid to everything, backgrounds and hearts don't collide at all
+    pub fn collision_layer(&self) -> Option<CollisionLayer> {
+        match self.my_type {
+            GameObjectTypes::Player => Some(CollisionLayer::Solid),
+            GameObjectTypes::Feature => self
+                .feature_type
+                .map(|feature_type| feature_type.collision_layer()),
+            GameObjectTypes::Heart | GameObjectTypes::Background => None,
         }
     }
 
@@ -64,21 +159,6 @@ impl GameObject {
     }
 }
 
-impl Clone for GameObject {
-    fn clone(&self) -> Self {
-        Self {
-            location: self.location,
-            width: self.width,
-            height: self.height,
-            draw_system: None,
-            life_system: None,
-            physics_system: None,
-            my_type: self.my_type,
-            feature_type: self.feature_type,
-        }
-    }
-}
-
 #[derive(Debug)]
 pub enum GameObjectBuilderError {
     MyTypeNotSet,
@@ -172,9 +252,13 @@ impl GameObjectBuilder {
             height: self.height,
             draw_system: self.draw_system,
             life_system: self.life_system,
-            physics_system: self.physics_system,
             my_type,
             feature_type: self.feature_type,
+            physics_state: self
+                .physics_system
+                .as_ref()
+                .map(|physics_system| physics_system.get_state()),
+            physics_system: self.physics_system,
         })
     }
 }
@@ -184,6 +268,8 @@ mod test {
     use ggez::nalgebra::Point2;
 
     use crate::draw_systems::player_draw_system::PlayerDrawSystem;
+    use crate::game_objects::builders::hazard::create_snake;
+    use crate::game_objects::builders::player::create_player;
     use crate::game_objects::game_object_types::GameObjectfeatureTypes;
     use crate::{
         config, life_systems::player_life_system::PlayerLifeSystem,
@@ -275,6 +361,22 @@ mod test {
         assert_eq!(game_object.is_offscreen_left(), false);
     }
 
+    #[test]
+    fn ci_test_hazards_ignore_an_invulnerable_player() {
+        let config = config::load("config.json").unwrap();
+        let snake = create_snake(&config).unwrap();
+        let touch_snake = Collision::with(&snake).unwrap();
+        let mut player = create_player(&config).unwrap();
+
+        player.collided(&touch_snake);
+        assert_eq!(player.get_lives(), Some(config.player_lives - 1));
+
+        player.location = snake.location;
+        player.collided(&touch_snake);
+        assert_eq!(player.get_lives(), Some(config.player_lives - 1));
+        assert_eq!(player.location, snake.location);
+    }
+
     #[test]
     fn ci_test_creating_pit1() {
         let pit1 = GameObjectBuilder::new()
@@ -1,3 +1,6 @@
+use crate::collisions::CollisionLayer;
+use crate::life_systems::DeathCause;
+
 #[derive(PartialEq, Debug, Eq, Hash, Clone, Copy)]
 pub enum GameObjectTypes {
     Player,
@@ -9,4 +12,62 @@ pub enum GameObjectTypes {
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum GameObjectfeatureTypes {
     Pit1,
+    Pit3,
+    Relic(usize),
+    Rope,
+    Ladder,
+    BrickWall,
+    Barrel,
+    TarPit,
+    Lake,
+    Alligator,
+    Snake,
+    Fire,
+    Scorpion,
+    Stalagmite,
+    Stalactite,
+}
+
+impl GameObjectfeatureTypes {
+    /// What touching this feature kills the player with, None for anything that's safe to touch.
+    /// Alligators only bite while their jaws are open
+    pub fn hazard(self) -> Option<DeathCause> {
+        match self {
+            GameObjectfeatureTypes::Snake => Some(DeathCause::Snake),
+            GameObjectfeatureTypes::Fire => Some(DeathCause::Fire),
+            GameObjectfeatureTypes::Scorpion => Some(DeathCause::Scorpion),
+            GameObjectfeatureTypes::Alligator => Some(DeathCause::Alligator),
+            _ => None,
+        }
+    }
+
+    /// What standing over this feature's opening drops the player into
+    pub fn fall_cause(self) -> Option<DeathCause> {
+        match self {
+            GameObjectfeatureTypes::Pit1 | GameObjectfeatureTypes::Pit3 => Some(DeathCause::Pit),
+            GameObjectfeatureTypes::TarPit => Some(DeathCause::TarPit),
+            GameObjectfeatureTypes::Lake => Some(DeathCause::Drowned),
+            _ => None,
+        }
+    }
+
+    pub fn collision_layer(self) -> CollisionLayer {
+        match self {
+            GameObjectfeatureTypes::Snake
+            | GameObjectfeatureTypes::Fire
+            | GameObjectfeatureTypes::Scorpion => CollisionLayer::Hazard,
+            GameObjectfeatureTypes::BrickWall
+            | GameObjectfeatureTypes::Barrel
+            | GameObjectfeatureTypes::Alligator
+            | GameObjectfeatureTypes::Stalagmite
+            | GameObjectfeatureTypes::Stalactite => CollisionLayer::Solid,
+            GameObjectfeatureTypes::Pit1
+            | GameObjectfeatureTypes::Pit3
+            | GameObjectfeatureTypes::Relic(_)
+            | GameObjectfeatureTypes::Rope
+            | GameObjectfeatureTypes::Ladder
+            | GameObjectfeatureTypes::TarPit
+            | GameObjectfeatureTypes::Lake => CollisionLayer::Trigger,
+        }
+    }
 }
@@ -4,20 +4,25 @@ pub mod game_object_types;
 
 use ggez::{Context, GameResult};
 
+use crate::collisions::{self, Collision};
 use crate::config::Config;
-use crate::handle_input::Command;
+use crate::handle_input::InputState;
 use crate::images::Images;
 
 pub use self::game_object::GameObject;
-pub use self::game_object_types::GameObjectTypes;
+pub use self::game_object_types::{GameObjectTypes, GameObjectfeatureTypes};
 
 pub struct GameObjects {
     objects: Vec<GameObject>,
+    colliders: Vec<(usize, Collision)>,
 }
 
 impl GameObjects {
     pub fn new() -> Self {
-        Self { objects: vec![] }
+        Self {
+            objects: vec![],
+            colliders: vec![],
+        }
     }
 
     pub fn push(&mut self, game_object: GameObject) {
@@ -33,11 +38,12 @@ impl GameObjects {
             .find(|game_object| game_object.my_type == game_object_type)
     }
 
-    pub fn update(&mut self, command: Option<Command>) {
-        let features = self.get_all_features();
-        self.objects
-            .iter_mut()
-            .for_each(|game_object| game_object.update(command, features.clone()));
+    pub fn update(&mut self, input: &InputState) {
+        for index in 0..self.objects.len() {
+            GameObject::update_in_place(&mut self.objects, index, input);
+        }
+
+        collisions::deliver(&mut self.objects, &mut self.colliders);
     }
 
     pub fn draw(
@@ -70,28 +76,53 @@ impl GameObjects {
         }
     }
 
-    pub fn get_all_features(&self) -> Vec<GameObject> {
+    #[cfg(test)]
+    pub fn get_all_features(&self) -> impl Iterator<Item = &GameObject> {
         self.objects
-            .clone()
-            .into_iter()
+            .iter()
             .filter(|game_object| game_object.my_type == GameObjectTypes::Feature)
-            .collect()
     }
 
     pub fn insert(&mut self, game_object: GameObject, index: usize) {
         self.objects.insert(index, game_object);
     }
+
+    /// Removes every relic the player touched this tick and returns their catalog indexes
+    pub fn collect_relics(&mut self) -> Vec<usize> {
+        let collected = match self.get_first_by_type(GameObjectTypes::Player) {
+            Some(player) => player.take_relics(),
+            None => return vec![],
+        };
+
+        self.objects
+            .retain(|game_object| match game_object.feature_type {
+                Some(GameObjectfeatureTypes::Relic(relic_index)) => {
+                    !collected.contains(&relic_index)
+                }
+                _ => true,
+            });
+
+        collected
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use std::time::{Duration, Instant};
+
+    use builders::barrel::create_barrel;
+    use builders::cave_rock::create_stalagmite;
+    use builders::hazard::create_scorpion;
     use builders::pit1::create_pit1;
     use builders::player::create_player;
+    use builders::relic::create_relic;
     use game_object::GameObjectBuilder;
 
     use crate::config;
     use crate::draw_systems::single_pit_draw_system::SinglePitDrawSystem;
+    use crate::handle_input::Command;
     use crate::initialize::initialize;
+    use crate::physics_systems::PhysicsState;
 
     use super::*;
 
@@ -133,9 +164,9 @@ mod test {
         let config = config::load("config.json").unwrap();
         let player = create_player(&config).unwrap();
         let mut game_objects = GameObjects::new();
-        let command = Command::MoveRight;
+        let input = InputState::new().press(Command::MoveRight);
         game_objects.push(player);
-        game_objects.update(Some(command));
+        game_objects.update(&input);
     }
 
     #[test]
@@ -190,20 +221,97 @@ mod test {
         let pit = create_pit1(config).unwrap();
         game_objects.push(pit);
 
-        let all_features: Vec<GameObject> = game_objects.get_all_features();
+        let all_features: Vec<&GameObject> = game_objects.get_all_features().collect();
         assert_eq!(all_features.len(), 1);
         assert_eq!(all_features[0].my_type, GameObjectTypes::Feature);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_update_keeps_every_system_in_place() {
+        let mut game_objects = GameObjects::new();
+        let config = &config::load("config.json").unwrap();
+        game_objects.push(create_pit1(config).unwrap());
+        game_objects.push(create_player(config).unwrap());
+
+        game_objects.update(&InputState::new().hold(Command::MoveRight));
+        game_objects.update(&InputState::new().hold(Command::MoveRight));
+
+        let player = &game_objects.objects[1];
+        assert_eq!(
+            player.location.x,
+            config.player_starting_x + config.player_speed * 2.0
+        );
+        assert_eq!(player.get_lives(), Some(config.player_lives));
+        assert_eq!(player.physics_state, Some(PhysicsState::MovingRight));
+    }
+
+    #[test]
+    fn ci_test_collect_relics_touching_the_player() {
+        let mut game_objects = GameObjects::new();
+        let config = &config::load("config.json").unwrap();
+        let mut player = create_player(config).unwrap();
+        let relic = create_relic(config, 0).unwrap();
+        let far_relic = create_relic(config, 1).unwrap();
+        player.location = relic.location;
+        game_objects.push(player);
+        game_objects.push(relic);
+        game_objects.update(&InputState::new());
+        assert_eq!(game_objects.collect_relics(), vec![0]);
+        assert_eq!(game_objects.objects.len(), 1);
+
+        game_objects.push(far_relic);
+        game_objects.objects[0].location.x = 0.0;
+        game_objects.update(&InputState::new());
+        assert_eq!(game_objects.collect_relics(), Vec::<usize>::new());
+        assert_eq!(game_objects.objects.len(), 2);
+    }
+
     #[test]
     fn ci_test_insert_into_objects() {
         let mut game_objects = GameObjects::new();
         let config = &config::load("config.json").unwrap();
         let pit = create_pit1(config).unwrap();
-        let player = create_player(&config).unwrap();
+        let player = create_player(config).unwrap();
         game_objects.push(player);
         game_objects.insert(pit, 0);
         assert_eq!(game_objects.objects[0].my_type, GameObjectTypes::Feature);
         assert_eq!(game_objects.objects[1].my_type, GameObjectTypes::Player);
     }
+
+    /// cargo test --release bench_update -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_update_hundreds_of_objects() {
+        let config = &config::load("config.json").unwrap();
+        let ticks = 1_000;
+
+        for &count in [100, 200, 400].iter() {
+            let mut game_objects = GameObjects::new();
+            for index in 0..count {
+                let x = (index * 7 % 1900) as f32;
+                let feature = match index % 3 {
+                    0 => create_barrel(config, index).unwrap(),
+                    1 => create_stalagmite(config, x).unwrap(),
+                    _ => {
+                        let mut scorpion = create_scorpion(config).unwrap();
+                        scorpion.location.x = x;
+                        scorpion
+                    }
+                };
+                game_objects.push(feature);
+            }
+            game_objects.push(create_player(config).unwrap());
+            let input = InputState::new().hold(Command::MoveRight);
+
+            let started = Instant::now();
+            for _ in 0..ticks {
+                game_objects.update(&input);
+            }
+            let per_tick = started.elapsed() / ticks;
+
+            println!("{} objects: {:?} per tick", count + 1, per_tick);
+            assert!(per_tick < Duration::from_secs(1) / crate::UPDATES_PER_SECOND);
+        }
+    }
 }
@@ -0,0 +1,67 @@
+use crate::UPDATES_PER_SECOND;
+
+/// Counts down once per fixed update, so pausing stops the clock
+pub struct GameTimer {
+    updates_left: u32,
+}
+
+impl GameTimer {
+    pub fn new(seconds: u32) -> Self {
+        Self {
+            updates_left: seconds * UPDATES_PER_SECOND,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.updates_left = self.updates_left.saturating_sub(1);
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.updates_left == 0
+    }
+
+    /// Whole seconds left, rounded up so the clock only reads 00:00 once it has run out
+    pub fn seconds_left(&self) -> u32 {
+        self.updates_left.div_ceil(UPDATES_PER_SECOND)
+    }
+
+    pub fn display(&self) -> String {
+        let seconds_left = self.seconds_left();
+        format!("{:02}:{:02}", seconds_left / 60, seconds_left % 60)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ci_test_timer_starts_full() {
+        let game_timer = GameTimer::new(1200);
+
+        assert_eq!(game_timer.seconds_left(), 1200);
+        assert_eq!(game_timer.display(), "20:00");
+        assert!(!game_timer.is_expired());
+    }
+
+    #[test]
+    fn ci_test_timer_counts_down_at_the_update_rate() {
+        let mut game_timer = GameTimer::new(61);
+
+        game_timer.update();
+        assert_eq!(game_timer.display(), "01:01");
+
+        (1..UPDATES_PER_SECOND).for_each(|_| game_timer.update());
+        assert_eq!(game_timer.display(), "01:00");
+    }
+
+    #[test]
+    fn ci_test_timer_expires() {
+        let mut game_timer = GameTimer::new(1);
+
+        (0..UPDATES_PER_SECOND + 5).for_each(|_| game_timer.update());
+
+        assert!(game_timer.is_expired());
+        assert_eq!(game_timer.display(), "00:00");
+    }
+}
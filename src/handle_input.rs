@@ -1,105 +1,324 @@
-use ggez::{event::Button, input::gamepad::Gilrs, GameResult};
+use std::collections::{HashSet, VecDeque};
+
+use ggez::event::{Axis, Button, KeyCode};
+use ggez::input::gamepad::gilrs::EventType;
+use ggez::{input::gamepad::Gilrs, GameResult};
 
 use crate::{config::Config, scenes::ActiveScene};
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum Command {
+    ClimbDown,
+    ClimbUp,
     Jump,
+    MenuDown,
+    MenuSelect,
+    MenuUp,
     MoveLeft,
     MoveRight,
+    Pause,
     StartGame,
-    StopMovingLeft,
-    StopMovingRight,
 }
 
-impl Command {
-    pub fn stop(&mut self) {
-        match self {
-            Command::Jump => {}
-            Command::MoveLeft => *self = Command::StopMovingLeft,
-            Command::MoveRight => *self = Command::StopMovingRight,
-            Command::StartGame => {}
-            Command::StopMovingLeft => {}
-            Command::StopMovingRight => {}
-        }
+/// Everything the player is doing with the controls during a single update
+/// tick. `held` is the state at the end of the tick, while `pressed` and
+/// `released` record the edges, so a button tapped between two ticks still
+/// shows up as pressed and released even though it is no longer held.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct InputState {
+    held: HashSet<Command>,
+    pressed: HashSet<Command>,
+    released: HashSet<Command>,
+}
+
+impl InputState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hold(mut self, command: Command) -> Self {
+        self.held.insert(command);
+        self
+    }
+
+    pub fn press(mut self, command: Command) -> Self {
+        self.held.insert(command);
+        self.pressed.insert(command);
+        self
+    }
+
+    pub fn release(mut self, command: Command) -> Self {
+        self.held.remove(&command);
+        self.released.insert(command);
+        self
     }
+
+    pub fn is_held(&self, command: Command) -> bool {
+        self.held.contains(&command)
+    }
+
+    pub fn just_pressed(&self, command: Command) -> bool {
+        self.pressed.contains(&command)
+    }
+
+    #[cfg(test)]
+    pub fn just_released(&self, command: Command) -> bool {
+        self.released.contains(&command)
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum InputEvent {
+    KeyPressed(KeyCode),
+    KeyReleased(KeyCode),
+    ButtonPressed(Button),
+    ButtonReleased(Button),
+    AxisChanged(Axis, f32),
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum StickDirection {
+    Left,
+    Centered,
+    Right,
 }
 
 pub struct HandleInput {
     gamepad: Gilrs,
-    start_button: Button,
-    move_right: Button,
-    move_left: Button,
-    jump_button_1: Button,
-    jump_button_2: Button,
-    jump_button_3: Button,
-    jump_button_4: Button,
+    events: VecDeque<InputEvent>,
+    held_buttons: HashSet<Button>,
+    held_keys: HashSet<KeyCode>,
+    start_buttons: Vec<Button>,
+    move_right_buttons: Vec<Button>,
+    move_left_buttons: Vec<Button>,
+    jump_buttons: Vec<Button>,
+    climb_up_buttons: Vec<Button>,
+    climb_down_buttons: Vec<Button>,
+    pause_buttons: Vec<Button>,
+    menu_up_buttons: Vec<Button>,
+    menu_down_buttons: Vec<Button>,
+    menu_select_buttons: Vec<Button>,
+    start_keys: Vec<KeyCode>,
+    move_right_keys: Vec<KeyCode>,
+    move_left_keys: Vec<KeyCode>,
+    jump_keys: Vec<KeyCode>,
+    climb_up_keys: Vec<KeyCode>,
+    climb_down_keys: Vec<KeyCode>,
+    pause_keys: Vec<KeyCode>,
+    menu_up_keys: Vec<KeyCode>,
+    menu_down_keys: Vec<KeyCode>,
+    menu_select_keys: Vec<KeyCode>,
+    stick_direction: StickDirection,
+    stick_dead_zone: f32,
+    stick_hysteresis: f32,
 }
 
 impl HandleInput {
     pub fn new(config: &Config) -> GameResult<Self> {
         let gamepad = Gilrs::new()?;
-        let start_button = config.start_button;
-        let move_right = config.move_right_button;
-        let move_left = config.move_left_button;
-        let jump_button_1 = Button::North;
-        let jump_button_2 = Button::East;
-        let jump_button_3 = Button::South;
-        let jump_button_4 = Button::West;
 
         Ok(Self {
             gamepad,
-            start_button,
-            move_right,
-            move_left,
-            jump_button_1,
-            jump_button_2,
-            jump_button_3,
-            jump_button_4,
+            events: VecDeque::new(),
+            held_buttons: HashSet::new(),
+            held_keys: HashSet::new(),
+            start_buttons: config.start_buttons.clone(),
+            move_right_buttons: config.move_right_buttons.clone(),
+            move_left_buttons: config.move_left_buttons.clone(),
+            jump_buttons: config.jump_buttons.clone(),
+            climb_up_buttons: config.climb_up_buttons.clone(),
+            climb_down_buttons: config.climb_down_buttons.clone(),
+            pause_buttons: config.pause_buttons.clone(),
+            menu_up_buttons: config.menu_up_buttons.clone(),
+            menu_down_buttons: config.menu_down_buttons.clone(),
+            menu_select_buttons: config.menu_select_buttons.clone(),
+            start_keys: config.start_keys.clone(),
+            move_right_keys: config.move_right_keys.clone(),
+            move_left_keys: config.move_left_keys.clone(),
+            jump_keys: config.jump_keys.clone(),
+            climb_up_keys: config.climb_up_keys.clone(),
+            climb_down_keys: config.climb_down_keys.clone(),
+            pause_keys: config.pause_keys.clone(),
+            menu_up_keys: config.menu_up_keys.clone(),
+            menu_down_keys: config.menu_down_keys.clone(),
+            menu_select_keys: config.menu_select_keys.clone(),
+            stick_direction: StickDirection::Centered,
+            stick_dead_zone: config.stick_dead_zone,
+            stick_hysteresis: config.stick_hysteresis,
         })
     }
 
-    pub fn run(&mut self, current_scene: &ActiveScene) -> Option<Command> {
-        if let Some(gamepad_event) = self.gamepad.next_event() {
+    /// Drains every keyboard and gamepad event that arrived since the last
+    /// tick and turns them into a snapshot for the current scene.
+    pub fn run(&mut self, current_scene: &ActiveScene) -> InputState {
+        let previously_held = self.held_commands(current_scene);
+        let mut event_presses = HashSet::new();
+
+        while let Some(gamepad_event) = self.gamepad.next_event() {
             match gamepad_event.event {
-                ggez::input::gamepad::gilrs::EventType::ButtonRepeated(button, _code) => {
-                    dbg!("hello", button);
-                    None
+                EventType::ButtonPressed(button, _code) => {
+                    self.events.push_back(InputEvent::ButtonPressed(button))
+                }
+                EventType::ButtonReleased(button, _code) => {
+                    self.events.push_back(InputEvent::ButtonReleased(button))
                 }
-                ggez::input::gamepad::gilrs::EventType::ButtonPressed(button, _code) => {
-                    self.button_to_command(button, current_scene)
+                EventType::AxisChanged(axis, value, _code) => {
+                    self.events.push_back(InputEvent::AxisChanged(axis, value))
                 }
-                ggez::input::gamepad::gilrs::EventType::ButtonReleased(button, _) => {
-                    let mut command = self.button_to_command(button, current_scene);
-                    if let Some(command) = &mut command {
-                        command.stop();
-                    }
+                EventType::ButtonRepeated(_, _)
+                | EventType::ButtonChanged(_, _, _)
+                | EventType::Connected
+                | EventType::Disconnected
+                | EventType::Dropped => {}
+            }
+        }
 
-                    command
+        while let Some(event) = self.events.pop_front() {
+            match event {
+                InputEvent::KeyPressed(keycode) => {
+                    self.held_keys.insert(keycode);
+                    event_presses.extend(self.key_to_command(keycode, current_scene));
+                }
+                InputEvent::KeyReleased(keycode) => {
+                    self.held_keys.remove(&keycode);
                 }
-                ggez::input::gamepad::gilrs::EventType::ButtonChanged(_, _, _) => None,
-                ggez::input::gamepad::gilrs::EventType::AxisChanged(_, _, _) => None,
-                ggez::input::gamepad::gilrs::EventType::Connected => None,
-                ggez::input::gamepad::gilrs::EventType::Disconnected => None,
-                ggez::input::gamepad::gilrs::EventType::Dropped => None,
+                InputEvent::ButtonPressed(button) => {
+                    self.held_buttons.insert(button);
+                    event_presses.extend(self.button_to_command(button, current_scene));
+                }
+                InputEvent::ButtonReleased(button) => {
+                    self.held_buttons.remove(&button);
+                }
+                InputEvent::AxisChanged(axis, value) => self.move_stick(axis, value),
             }
-        } else {
-            None
         }
+
+        let held = self.held_commands(current_scene);
+        let pressed: HashSet<Command> = event_presses
+            .union(&held)
+            .filter(|command| !previously_held.contains(command))
+            .copied()
+            .collect();
+        let released: HashSet<Command> = previously_held
+            .union(&pressed)
+            .filter(|command| !held.contains(command))
+            .copied()
+            .collect();
+
+        let input_state = held.iter().fold(InputState::new(), |input_state, command| {
+            input_state.hold(*command)
+        });
+        let input_state = pressed.iter().fold(input_state, |input_state, command| {
+            input_state.press(*command)
+        });
+        released.iter().fold(input_state, |input_state, command| {
+            input_state.release(*command)
+        })
+    }
+
+    fn held_commands(&self, current_scene: &ActiveScene) -> HashSet<Command> {
+        let stick_command = match self.stick_direction {
+            StickDirection::Left => Some(Command::MoveLeft),
+            StickDirection::Centered => None,
+            StickDirection::Right => Some(Command::MoveRight),
+        }
+        .filter(|_| *current_scene == ActiveScene::Main);
+
+        self.held_buttons
+            .iter()
+            .filter_map(|button| self.button_to_command(*button, current_scene))
+            .chain(
+                self.held_keys
+                    .iter()
+                    .filter_map(|keycode| self.key_to_command(*keycode, current_scene)),
+            )
+            .chain(stick_command)
+            .collect()
     }
 
     fn button_to_command(&self, button: Button, current_scene: &ActiveScene) -> Option<Command> {
-        match (button, current_scene) {
-            (button, ActiveScene::Start) if button == self.start_button => Some(Command::StartGame),
-            (button, ActiveScene::Main) if button == self.move_right => Some(Command::MoveRight),
-            (button, ActiveScene::Main) if button == self.move_left => Some(Command::MoveLeft),
-            (button, ActiveScene::Main)
-                if button == self.jump_button_1
-                    || button == self.jump_button_2
-                    || button == self.jump_button_3
-                    || button == self.jump_button_4 =>
-            {
-                Some(Command::Jump)
+        match current_scene {
+            ActiveScene::Start if self.start_buttons.contains(&button) => Some(Command::StartGame),
+            ActiveScene::Main if self.move_right_buttons.contains(&button) => {
+                Some(Command::MoveRight)
+            }
+            ActiveScene::Main if self.move_left_buttons.contains(&button) => {
+                Some(Command::MoveLeft)
+            }
+            ActiveScene::Main if self.jump_buttons.contains(&button) => Some(Command::Jump),
+            ActiveScene::Main if self.climb_up_buttons.contains(&button) => Some(Command::ClimbUp),
+            ActiveScene::Main if self.climb_down_buttons.contains(&button) => {
+                Some(Command::ClimbDown)
+            }
+            ActiveScene::Main | ActiveScene::Pause if self.pause_buttons.contains(&button) => {
+                Some(Command::Pause)
+            }
+            ActiveScene::Pause if self.menu_up_buttons.contains(&button) => Some(Command::MenuUp),
+            ActiveScene::Pause if self.menu_down_buttons.contains(&button) => {
+                Some(Command::MenuDown)
+            }
+            ActiveScene::Pause | ActiveScene::End if self.menu_select_buttons.contains(&button) => {
+                Some(Command::MenuSelect)
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves with the left stick like the D-pad. The stick has to come back
+    /// past the dead zone by `stick_hysteresis` before we stop, so hovering
+    /// right on the edge of the dead zone doesn't flood us with start/stop
+    /// commands.
+    fn move_stick(&mut self, axis: Axis, value: f32) {
+        if axis != Axis::LeftStickX {
+            return;
+        }
+
+        let release_zone = self.stick_dead_zone - self.stick_hysteresis;
+        self.stick_direction = if value >= self.stick_dead_zone {
+            StickDirection::Right
+        } else if value <= -self.stick_dead_zone {
+            StickDirection::Left
+        } else if value.abs() < release_zone {
+            StickDirection::Centered
+        } else {
+            // only hold on to the direction the stick is still leaning towards
+            match self.stick_direction {
+                StickDirection::Right if value > 0.0 => StickDirection::Right,
+                StickDirection::Left if value < 0.0 => StickDirection::Left,
+                _ => StickDirection::Centered,
+            }
+        };
+    }
+
+    pub fn key_pressed(&mut self, keycode: KeyCode, repeat: bool) {
+        if !repeat {
+            self.events.push_back(InputEvent::KeyPressed(keycode));
+        }
+    }
+
+    pub fn key_released(&mut self, keycode: KeyCode) {
+        self.events.push_back(InputEvent::KeyReleased(keycode));
+    }
+
+    fn key_to_command(&self, keycode: KeyCode, current_scene: &ActiveScene) -> Option<Command> {
+        match current_scene {
+            ActiveScene::Start if self.start_keys.contains(&keycode) => Some(Command::StartGame),
+            ActiveScene::Main if self.move_right_keys.contains(&keycode) => {
+                Some(Command::MoveRight)
+            }
+            ActiveScene::Main if self.move_left_keys.contains(&keycode) => Some(Command::MoveLeft),
+            ActiveScene::Main if self.jump_keys.contains(&keycode) => Some(Command::Jump),
+            ActiveScene::Main if self.climb_up_keys.contains(&keycode) => Some(Command::ClimbUp),
+            ActiveScene::Main if self.climb_down_keys.contains(&keycode) => {
+                Some(Command::ClimbDown)
+            }
+            ActiveScene::Main | ActiveScene::Pause if self.pause_keys.contains(&keycode) => {
+                Some(Command::Pause)
+            }
+            ActiveScene::Pause if self.menu_up_keys.contains(&keycode) => Some(Command::MenuUp),
+            ActiveScene::Pause if self.menu_down_keys.contains(&keycode) => Some(Command::MenuDown),
+            ActiveScene::Pause | ActiveScene::End if self.menu_select_keys.contains(&keycode) => {
+                Some(Command::MenuSelect)
             }
             _ => None,
         }
@@ -116,7 +335,7 @@ mod test {
     fn ci_test_create_handle_input() {
         let config = config::load("config.json").unwrap();
         let handle_input = HandleInput::new(&config).unwrap();
-        assert_eq!(handle_input.start_button, config.start_button);
+        assert_eq!(handle_input.start_buttons, config.start_buttons);
     }
 
     #[test]
@@ -125,7 +344,7 @@ mod test {
         let config = config::load("config.json").unwrap();
         let handle_input = HandleInput::new(&config).unwrap();
         let command = handle_input
-            .button_to_command(config.start_button, &start_scene)
+            .button_to_command(config.start_buttons[0], &start_scene)
             .unwrap();
         assert_eq!(command, Command::StartGame);
     }
@@ -135,7 +354,7 @@ mod test {
         let scene = ActiveScene::End;
         let config = config::load("config.json").unwrap();
         let handle_input = HandleInput::new(&config).unwrap();
-        let command = handle_input.button_to_command(config.start_button, &scene);
+        let command = handle_input.button_to_command(config.start_buttons[0], &scene);
         assert_eq!(command, None);
     }
 
@@ -145,23 +364,29 @@ mod test {
         let config = config::load("config.json").unwrap();
         let handle_input = HandleInput::new(&config).unwrap();
         let command = handle_input
-            .button_to_command(config.move_right_button, &scene)
+            .button_to_command(config.move_right_buttons[0], &scene)
             .unwrap();
         assert_eq!(command, Command::MoveRight);
     }
 
     #[test]
-    fn ci_test_stop_moving_when_moving() {
-        let mut moving = Command::MoveRight;
-        moving.stop();
-        assert_eq!(moving, Command::StopMovingRight);
+    fn ci_test_input_state_press_and_release() {
+        let pressed = InputState::new().press(Command::MoveRight);
+        assert!(pressed.is_held(Command::MoveRight));
+        assert!(pressed.just_pressed(Command::MoveRight));
+        assert!(!pressed.just_released(Command::MoveRight));
+
+        let released = InputState::new().release(Command::MoveRight);
+        assert!(!released.is_held(Command::MoveRight));
+        assert!(released.just_released(Command::MoveRight));
     }
 
     #[test]
-    fn ci_test_stopping_when_stopped_doesnt_do_anything() {
-        let mut stopped_moving = Command::StopMovingRight;
-        stopped_moving.stop();
-        assert_eq!(stopped_moving, Command::StopMovingRight);
+    fn ci_test_input_state_held_is_not_an_edge() {
+        let held = InputState::new().hold(Command::MoveLeft);
+        assert!(held.is_held(Command::MoveLeft));
+        assert!(!held.just_pressed(Command::MoveLeft));
+        assert!(!held.just_released(Command::MoveLeft));
     }
 
     #[test]
@@ -170,26 +395,312 @@ mod test {
         let config = config::load("config.json").unwrap();
         let handle_input = HandleInput::new(&config).unwrap();
         let command = handle_input
-            .button_to_command(config.move_left_button, &scene)
+            .button_to_command(config.move_left_buttons[0], &scene)
             .unwrap();
         assert_eq!(command, Command::MoveLeft);
     }
 
-    #[test]
-    fn ci_test_stopping_left_button_while_moving() {
-        let mut command = Command::MoveLeft;
-        command.stop();
-        assert_eq!(command, Command::StopMovingLeft);
-    }
-
     #[test]
     fn ci_test_jumping() {
         let scene = ActiveScene::Main;
         let config = Config::default();
         let handle_input = HandleInput::new(&config).unwrap();
         let command = handle_input
-            .button_to_command(config.jump_button, &scene)
+            .button_to_command(config.jump_buttons[0], &scene)
             .unwrap();
         assert_eq!(command, Command::Jump);
     }
+
+    #[test]
+    fn ci_test_keyboard_start_game() {
+        let config = config::load("config.json").unwrap();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+        handle_input.key_pressed(config.start_keys[0], false);
+        assert!(handle_input
+            .run(&ActiveScene::Start)
+            .just_pressed(Command::StartGame));
+    }
+
+    #[test]
+    fn ci_test_keyboard_move_right_held_over_ticks() {
+        let scene = ActiveScene::Main;
+        let config = config::load("config.json").unwrap();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+
+        handle_input.key_pressed(config.move_right_keys[0], false);
+        let input_state = handle_input.run(&scene);
+        assert!(input_state.just_pressed(Command::MoveRight));
+        assert!(input_state.is_held(Command::MoveRight));
+
+        let input_state = handle_input.run(&scene);
+        assert!(!input_state.just_pressed(Command::MoveRight));
+        assert!(input_state.is_held(Command::MoveRight));
+
+        handle_input.key_released(config.move_right_keys[0]);
+        let input_state = handle_input.run(&scene);
+        assert!(input_state.just_released(Command::MoveRight));
+        assert!(!input_state.is_held(Command::MoveRight));
+    }
+
+    #[test]
+    fn ci_test_keyboard_simultaneous_presses_arrive_in_one_tick() {
+        let scene = ActiveScene::Main;
+        let config = config::load("config.json").unwrap();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+
+        handle_input.key_pressed(config.move_right_keys[0], false);
+        handle_input.key_pressed(config.jump_keys[0], false);
+        let input_state = handle_input.run(&scene);
+
+        assert!(input_state.just_pressed(Command::MoveRight));
+        assert!(input_state.just_pressed(Command::Jump));
+    }
+
+    #[test]
+    fn ci_test_keyboard_tap_between_ticks_is_not_lost() {
+        let scene = ActiveScene::Main;
+        let config = config::load("config.json").unwrap();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+
+        handle_input.key_pressed(config.jump_keys[0], false);
+        handle_input.key_pressed(config.jump_keys[0], true);
+        handle_input.key_released(config.jump_keys[0]);
+        let input_state = handle_input.run(&scene);
+
+        assert!(input_state.just_pressed(Command::Jump));
+        assert!(input_state.just_released(Command::Jump));
+        assert!(!input_state.is_held(Command::Jump));
+    }
+
+    #[test]
+    fn ci_test_second_binding_for_held_command_is_not_a_new_press() {
+        let scene = ActiveScene::Main;
+        let config = Config {
+            move_left_keys: vec![KeyCode::Left, KeyCode::A],
+            ..Config::default()
+        };
+        let mut handle_input = HandleInput::new(&config).unwrap();
+
+        handle_input.key_pressed(KeyCode::Left, false);
+        handle_input.run(&scene);
+        handle_input.key_pressed(KeyCode::A, false);
+        handle_input.key_released(KeyCode::Left);
+        let input_state = handle_input.run(&scene);
+
+        assert!(input_state.is_held(Command::MoveLeft));
+        assert!(!input_state.just_pressed(Command::MoveLeft));
+        assert!(!input_state.just_released(Command::MoveLeft));
+    }
+
+    #[test]
+    fn ci_test_keyboard_move_left_in_wrong_scene() {
+        let config = config::load("config.json").unwrap();
+        let handle_input = HandleInput::new(&config).unwrap();
+        assert_eq!(
+            handle_input.key_to_command(config.move_left_keys[0], &ActiveScene::Start),
+            None
+        );
+    }
+
+    #[test]
+    fn ci_test_only_configured_jump_buttons_jump() {
+        let scene = ActiveScene::Main;
+        let config = Config {
+            jump_buttons: vec![Button::East, Button::RightTrigger],
+            ..Config::default()
+        };
+        let handle_input = HandleInput::new(&config).unwrap();
+
+        assert_eq!(
+            handle_input.button_to_command(Button::East, &scene),
+            Some(Command::Jump)
+        );
+        assert_eq!(
+            handle_input.button_to_command(Button::RightTrigger, &scene),
+            Some(Command::Jump)
+        );
+        assert_eq!(handle_input.button_to_command(Button::South, &scene), None);
+    }
+
+    #[test]
+    fn ci_test_every_move_key_is_bound() {
+        let scene = ActiveScene::Main;
+        let config = Config {
+            move_left_keys: vec![KeyCode::Left, KeyCode::A],
+            ..Config::default()
+        };
+        let handle_input = HandleInput::new(&config).unwrap();
+
+        assert_eq!(
+            handle_input.key_to_command(KeyCode::A, &scene),
+            Some(Command::MoveLeft)
+        );
+        assert_eq!(
+            handle_input.key_to_command(KeyCode::Left, &scene),
+            Some(Command::MoveLeft)
+        );
+    }
+
+    #[test]
+    fn ci_test_left_stick_moves_and_stops() {
+        let scene = ActiveScene::Main;
+        let config = Config::default();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+
+        move_left_stick(&mut handle_input, 0.9);
+        assert!(handle_input.run(&scene).just_pressed(Command::MoveRight));
+        move_left_stick(&mut handle_input, 0.95);
+        assert!(handle_input.run(&scene).is_held(Command::MoveRight));
+        move_left_stick(&mut handle_input, 0.0);
+        assert!(handle_input.run(&scene).just_released(Command::MoveRight));
+        move_left_stick(&mut handle_input, -0.9);
+        assert!(handle_input.run(&scene).just_pressed(Command::MoveLeft));
+    }
+
+    #[test]
+    fn ci_test_left_stick_hovering_at_dead_zone_only_moves_once() {
+        let config = Config::default();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+        let just_inside = config.stick_dead_zone - config.stick_hysteresis / 2.0;
+
+        handle_input.move_stick(Axis::LeftStickX, config.stick_dead_zone);
+        assert_eq!(handle_input.stick_direction, StickDirection::Right);
+        for _ in 0..5 {
+            handle_input.move_stick(Axis::LeftStickX, just_inside);
+            assert_eq!(handle_input.stick_direction, StickDirection::Right);
+            handle_input.move_stick(Axis::LeftStickX, config.stick_dead_zone);
+            assert_eq!(handle_input.stick_direction, StickDirection::Right);
+        }
+    }
+
+    #[test]
+    fn ci_test_left_stick_flipping_direction() {
+        let scene = ActiveScene::Main;
+        let config = Config::default();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+
+        move_left_stick(&mut handle_input, 1.0);
+        handle_input.run(&scene);
+        move_left_stick(&mut handle_input, -1.0);
+        let input_state = handle_input.run(&scene);
+
+        assert!(input_state.just_released(Command::MoveRight));
+        assert!(input_state.just_pressed(Command::MoveLeft));
+    }
+
+    #[test]
+    fn ci_test_left_stick_flicked_into_the_other_side_of_the_dead_zone() {
+        let scene = ActiveScene::Main;
+        let config = Config::default();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+        let just_inside = config.stick_dead_zone - config.stick_hysteresis / 2.0;
+
+        move_left_stick(&mut handle_input, 1.0);
+        handle_input.run(&scene);
+        move_left_stick(&mut handle_input, -just_inside);
+        let input_state = handle_input.run(&scene);
+
+        assert_eq!(handle_input.stick_direction, StickDirection::Centered);
+        assert!(input_state.just_released(Command::MoveRight));
+        assert!(!input_state.is_held(Command::MoveLeft));
+    }
+
+    #[test]
+    fn ci_test_other_axes_are_ignored() {
+        let config = Config::default();
+        let mut handle_input = HandleInput::new(&config).unwrap();
+
+        handle_input.move_stick(Axis::LeftStickY, 1.0);
+        assert_eq!(handle_input.stick_direction, StickDirection::Centered);
+        move_left_stick(&mut handle_input, 1.0);
+        assert!(!handle_input
+            .run(&ActiveScene::Start)
+            .is_held(Command::MoveRight));
+    }
+
+    #[test]
+    fn ci_test_start_button_pauses_and_resumes() {
+        let config = Config::default();
+        let handle_input = HandleInput::new(&config).unwrap();
+
+        assert_eq!(
+            handle_input.button_to_command(Button::Start, &ActiveScene::Main),
+            Some(Command::Pause)
+        );
+        assert_eq!(
+            handle_input.button_to_command(Button::Start, &ActiveScene::Pause),
+            Some(Command::Pause)
+        );
+        assert_eq!(
+            handle_input.button_to_command(Button::Start, &ActiveScene::Start),
+            Some(Command::StartGame)
+        );
+        assert_eq!(
+            handle_input.key_to_command(KeyCode::Escape, &ActiveScene::Main),
+            Some(Command::Pause)
+        );
+    }
+
+    #[test]
+    fn ci_test_menu_navigation_only_while_paused() {
+        let config = Config::default();
+        let handle_input = HandleInput::new(&config).unwrap();
+
+        assert_eq!(
+            handle_input.key_to_command(KeyCode::Down, &ActiveScene::Pause),
+            Some(Command::MenuDown)
+        );
+        assert_eq!(
+            handle_input.button_to_command(Button::South, &ActiveScene::Pause),
+            Some(Command::MenuSelect)
+        );
+        assert_eq!(
+            handle_input.button_to_command(Button::South, &ActiveScene::Main),
+            Some(Command::Jump)
+        );
+        assert_eq!(
+            handle_input.key_to_command(KeyCode::Up, &ActiveScene::Main),
+            Some(Command::ClimbUp)
+        );
+    }
+
+    #[test]
+    fn ci_test_climbing_bindings() {
+        let config = config::load("config.json").unwrap();
+        let handle_input = HandleInput::new(&config).unwrap();
+
+        assert_eq!(
+            handle_input.button_to_command(config.climb_up_buttons[0], &ActiveScene::Main),
+            Some(Command::ClimbUp)
+        );
+        assert_eq!(
+            handle_input.key_to_command(config.climb_down_keys[0], &ActiveScene::Main),
+            Some(Command::ClimbDown)
+        );
+        assert_eq!(
+            handle_input.key_to_command(config.climb_down_keys[0], &ActiveScene::Pause),
+            Some(Command::MenuDown)
+        );
+    }
+
+    #[test]
+    fn ci_test_menu_select_leaves_the_end_scene() {
+        let config = Config::default();
+        let handle_input = HandleInput::new(&config).unwrap();
+
+        assert_eq!(
+            handle_input.key_to_command(KeyCode::Return, &ActiveScene::End),
+            Some(Command::MenuSelect)
+        );
+        assert_eq!(
+            handle_input.key_to_command(KeyCode::Down, &ActiveScene::End),
+            None
+        );
+    }
+
+    fn move_left_stick(handle_input: &mut HandleInput, value: f32) {
+        handle_input
+            .events
+            .push_back(InputEvent::AxisChanged(Axis::LeftStickX, value));
+    }
 }
@@ -13,6 +13,13 @@ pub struct Images {
     pub trees: Mesh,
     pub foliage: Mesh,
     pub pit1: Mesh,
+    pub relics: Vec<Image>,
+    pub barrel: Image,
+    pub snake: Image,
+    pub fire: Image,
+    pub scorpion: Image,
+    pub stalagmite: Mesh,
+    pub stalactite: Mesh,
 }
 
 impl Images {
@@ -25,6 +32,17 @@ impl Images {
             trees: Self::create_trees(context, config)?,
             foliage: Self::create_foliage(context, config)?,
             pit1: Self::create_single_pit(config, context)?,
+            relics: config
+                .relics
+                .iter()
+                .map(|relic| Image::new(context, &relic.image))
+                .collect::<GameResult<Vec<Image>>>()?,
+            barrel: Image::new(context, &config.barrel_spritesheet)?,
+            snake: Image::new(context, &config.snake_spritesheet)?,
+            fire: Image::new(context, &config.fire_spritesheet)?,
+            scorpion: Image::new(context, &config.scorpion_spritesheet)?,
+            stalagmite: Self::create_stalagmite(context, config)?,
+            stalactite: Self::create_stalactite(context, config)?,
         })
     }
 
@@ -122,6 +140,41 @@ impl Images {
         mesh.build(context)
     }
 
+    /// A jagged spike centered on the origin, pointing up
+    fn create_stalagmite(context: &mut Context, config: &Config) -> GameResult<Mesh> {
+        let half_width = config.stalagmite_width / 2.0;
+        let half_height = config.stalagmite_height / 2.0;
+        let points = [
+            Point2::new(-half_width, half_height),
+            Point2::new(-half_width / 3.0, -half_height / 3.0),
+            Point2::new(-half_width / 6.0, -half_height / 6.0),
+            Point2::new(0.0, -half_height),
+            Point2::new(half_width / 3.0, 0.0),
+            Point2::new(half_width, half_height),
+        ];
+
+        MeshBuilder::new()
+            .polygon(DrawMode::fill(), &points, config.cave_rock_color)?
+            .build(context)
+    }
+
+    /// A jagged spike centered on the origin, pointing down
+    fn create_stalactite(context: &mut Context, config: &Config) -> GameResult<Mesh> {
+        let half_width = config.stalactite_width / 2.0;
+        let half_height = config.stalactite_height / 2.0;
+        let points = [
+            Point2::new(-half_width, -half_height),
+            Point2::new(half_width, -half_height),
+            Point2::new(half_width / 3.0, half_height / 3.0),
+            Point2::new(0.0, half_height),
+            Point2::new(-half_width / 4.0, half_height / 4.0),
+        ];
+
+        MeshBuilder::new()
+            .polygon(DrawMode::fill(), &points, config.cave_rock_color)?
+            .build(context)
+    }
+
     fn create_single_pit(config: &Config, context: &mut Context) -> GameResult<Mesh> {
         MeshBuilder::new()
             .rectangle(
@@ -1,30 +1,28 @@
+mod collisions;
 pub mod config;
 mod draw_systems;
 mod game_objects;
+mod game_timer;
 mod handle_input;
 mod images;
 pub mod initialize;
 mod life_systems;
 mod physics_systems;
 mod scenes;
+mod score;
 
 use config::Config;
-use ggez::event::EventHandler;
+use ggez::event::{self, EventHandler, KeyCode, KeyMods};
 use ggez::{graphics, Context, GameResult};
 use ggez::{graphics::BLACK, timer};
 use handle_input::HandleInput;
 use images::Images;
-use scenes::{
-    end_scene::EndScene, main_scene::MainScene, pause_scene::PauseScene, start_scene::StartScene,
-    ActiveScene,
-};
+use scenes::{scene_stack::SceneStack, start_scene::StartScene};
+
+pub const UPDATES_PER_SECOND: u32 = 30;
 
 pub struct GameState {
-    active_scene: ActiveScene,
-    starting_scene: StartScene,
-    main_scene: MainScene,
-    pause_scene: PauseScene,
-    end_scene: EndScene,
+    scenes: SceneStack,
     handle_input: HandleInput,
     config: Config,
     images: Images,
@@ -32,20 +30,12 @@ pub struct GameState {
 
 impl GameState {
     pub fn new(config: Config, context: &mut Context) -> GameResult<Self> {
-        let active_scene = ActiveScene::Start;
-        let starting_scene = StartScene::new(&config, context);
-        let mut images = Images::new(context, &config)?;
-        let main_scene = MainScene::new(&config, context, &mut images)?;
-        let pause_scene = PauseScene::new();
-        let end_scene = EndScene::new();
+        let scenes = SceneStack::new(Box::new(StartScene::new(&config)), &config)?;
+        let images = Images::new(context, &config)?;
         let handle_input = HandleInput::new(&config)?;
 
         Ok(Self {
-            active_scene,
-            starting_scene,
-            main_scene,
-            pause_scene,
-            end_scene,
+            scenes,
             handle_input,
             config,
             images,
@@ -55,19 +45,12 @@ impl GameState {
 
 impl EventHandler for GameState {
     fn update(&mut self, context: &mut Context) -> GameResult {
-        while timer::check_update_time(context, 30) {
-            let command = self.handle_input.run(&self.active_scene);
-
-            match self.active_scene {
-                ActiveScene::Start => self
-                    .starting_scene
-                    .update(command, &mut self.active_scene)?,
-                ActiveScene::Main => {
-                    self.main_scene
-                        .update(command, &self.config, &mut self.images, context)?
-                }
-                ActiveScene::Pause => self.pause_scene.update()?,
-                ActiveScene::End => self.end_scene.update()?,
+        while timer::check_update_time(context, UPDATES_PER_SECOND) {
+            let input = self.handle_input.run(&self.scenes.active_scene());
+            self.scenes.update(&input, &self.config)?;
+
+            if self.scenes.is_empty() {
+                event::quit(context);
             }
         }
 
@@ -77,21 +60,30 @@ impl EventHandler for GameState {
     fn draw(&mut self, context: &mut Context) -> GameResult {
         graphics::clear(context, BLACK);
 
-        match self.active_scene {
-            ActiveScene::Start => self.starting_scene.draw(context)?,
-            ActiveScene::Main => self
-                .main_scene
-                .draw(context, &self.config, &mut self.images)?,
-            ActiveScene::Pause => self.pause_scene.draw(context)?,
-            ActiveScene::End => self.end_scene.draw(context)?,
-        }
+        self.scenes.draw(context, &self.config, &mut self.images)?;
 
         graphics::present(context)
     }
+
+    fn key_down_event(
+        &mut self,
+        _context: &mut Context,
+        keycode: KeyCode,
+        _keymods: KeyMods,
+        repeat: bool,
+    ) {
+        self.handle_input.key_pressed(keycode, repeat);
+    }
+
+    fn key_up_event(&mut self, _context: &mut Context, keycode: KeyCode, _keymods: KeyMods) {
+        self.handle_input.key_released(keycode);
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use scenes::ActiveScene;
+
     use super::*;
 
     #[test]
@@ -100,6 +92,6 @@ mod test {
         let (context, _) = &mut initialize::initialize(&config).unwrap();
         let game_state = GameState::new(config, context).unwrap();
 
-        assert_eq!(game_state.active_scene, ActiveScene::Start);
+        assert_eq!(game_state.scenes.active_scene(), ActiveScene::Start);
     }
 }
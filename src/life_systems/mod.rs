@@ -2,9 +2,58 @@ pub mod player_life_system;
 
 use std::fmt::Debug;
 
+use ggez::nalgebra::Point2;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum DeathCause {
+    Pit,
+    TarPit,
+    Drowned,
+    Alligator,
+    Snake,
+    Fire,
+    Scorpion,
+    OutOfTime,
+}
+
+impl DeathCause {
+    pub fn description(self) -> &'static str {
+        match self {
+            DeathCause::Pit => "Fell into a pit",
+            DeathCause::TarPit => "Sank into a tar pit",
+            DeathCause::Drowned => "Drowned in a lake",
+            DeathCause::Alligator => "Eaten by an alligator",
+            DeathCause::Snake => "Bitten by a snake",
+            DeathCause::Fire => "Burned in a fire",
+            DeathCause::Scorpion => "Stung by a scorpion",
+            DeathCause::OutOfTime => "Ran out of time",
+        }
+    }
+
+    /// Falls leave us in the cave, so they send us back even when no life is lost
+    pub fn is_fall(self) -> bool {
+        matches!(
+            self,
+            DeathCause::Pit | DeathCause::TarPit | DeathCause::Drowned
+        )
+    }
+}
+
 pub trait LifeSystem
 where
     Self: Debug,
 {
     fn get_lives(&self) -> u8;
+
+    /// Returns false without costing a life while invulnerable
+    fn lose_life(&mut self, cause: DeathCause) -> bool;
+
+    /// What cost the most recent life
+    fn get_death_cause(&self) -> Option<DeathCause>;
+
+    fn respawn_location(&self) -> Point2<f32>;
+
+    fn is_invulnerable(&self) -> bool;
+
+    fn update(&mut self);
 }
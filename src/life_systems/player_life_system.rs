@@ -1,13 +1,35 @@
-use super::LifeSystem;
+use ggez::nalgebra::Point2;
+
+use super::{DeathCause, LifeSystem};
 
 #[derive(Debug)]
 pub struct PlayerLifeSystem {
     lives: u8,
+    respawn_location: Point2<f32>,
+    invulnerable_ticks: u16,
+    invulnerable_for: u16,
+    death_cause: Option<DeathCause>,
 }
 
 impl PlayerLifeSystem {
     pub fn new(lives: u8) -> Self {
-        Self { lives }
+        Self {
+            lives,
+            respawn_location: Point2::new(0.0, 0.0),
+            invulnerable_ticks: 0,
+            invulnerable_for: 0,
+            death_cause: None,
+        }
+    }
+
+    pub fn set_respawn_location(mut self, respawn_location: Point2<f32>) -> Self {
+        self.respawn_location = respawn_location;
+        self
+    }
+
+    pub fn set_invulnerable_ticks(mut self, invulnerable_ticks: u16) -> Self {
+        self.invulnerable_ticks = invulnerable_ticks;
+        self
     }
 }
 
@@ -15,6 +37,33 @@ impl LifeSystem for PlayerLifeSystem {
     fn get_lives(&self) -> u8 {
         self.lives
     }
+
+    fn lose_life(&mut self, cause: DeathCause) -> bool {
+        if self.is_invulnerable() {
+            return false;
+        }
+
+        self.lives = self.lives.saturating_sub(1);
+        self.invulnerable_for = self.invulnerable_ticks;
+        self.death_cause = Some(cause);
+        true
+    }
+
+    fn get_death_cause(&self) -> Option<DeathCause> {
+        self.death_cause
+    }
+
+    fn respawn_location(&self) -> Point2<f32> {
+        self.respawn_location
+    }
+
+    fn is_invulnerable(&self) -> bool {
+        self.invulnerable_for > 0
+    }
+
+    fn update(&mut self) {
+        self.invulnerable_for = self.invulnerable_for.saturating_sub(1);
+    }
 }
 
 #[cfg(test)]
@@ -27,4 +76,32 @@ mod test {
 
         assert_eq!(player_life_system.lives, 3);
     }
+
+    #[test]
+    fn ci_test_losing_a_life_makes_the_player_invulnerable() {
+        let mut player_life_system = PlayerLifeSystem::new(3).set_invulnerable_ticks(2);
+
+        assert!(player_life_system.lose_life(DeathCause::Pit));
+        assert_eq!(player_life_system.get_lives(), 2);
+        assert!(player_life_system.is_invulnerable());
+        assert!(!player_life_system.lose_life(DeathCause::Pit));
+        assert_eq!(player_life_system.get_lives(), 2);
+
+        player_life_system.update();
+        player_life_system.update();
+        assert!(!player_life_system.is_invulnerable());
+        assert!(player_life_system.lose_life(DeathCause::Pit));
+        assert_eq!(player_life_system.get_lives(), 1);
+    }
+
+    #[test]
+    fn ci_test_lives_stop_at_zero() {
+        let mut player_life_system = PlayerLifeSystem::new(1);
+
+        player_life_system.lose_life(DeathCause::Pit);
+        player_life_system.lose_life(DeathCause::Pit);
+
+        assert_eq!(player_life_system.get_lives(), 0);
+        assert_eq!(player_life_system.get_death_cause(), Some(DeathCause::Pit));
+    }
 }
@@ -0,0 +1,83 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::game_objects::GameObject;
+use crate::handle_input::InputState;
+
+use super::{PhysicsState, PhysicsSystem};
+
+/// Each head keeps its own timer, jaws stay closed for a while and then snap open
+pub struct AlligatorPhysicsSystem {
+    ticks: u32,
+    closed_ticks: u32,
+    open_ticks: u32,
+}
+
+impl AlligatorPhysicsSystem {
+    /// Heads start spread evenly through the cycle by their index so they don't all snap at once
+    pub fn new(config: &Config, index: usize) -> Self {
+        let cycle_ticks = config.alligator_closed_ticks + config.alligator_open_ticks;
+        let phase = cycle_ticks as usize * index / config.alligator_count.max(1);
+
+        Self {
+            ticks: phase as u32 % cycle_ticks.max(1),
+            closed_ticks: config.alligator_closed_ticks,
+            open_ticks: config.alligator_open_ticks,
+        }
+    }
+}
+
+impl PhysicsSystem for AlligatorPhysicsSystem {
+    fn update(&mut self, _location: &mut Point2<f32>, _input: &InputState, _world: &[GameObject]) {
+        self.ticks = (self.ticks + 1) % (self.closed_ticks + self.open_ticks).max(1);
+    }
+
+    fn get_state(&self) -> PhysicsState {
+        if self.ticks < self.closed_ticks {
+            PhysicsState::Closed
+        } else {
+            PhysicsState::Open
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ci_test_alligator_jaws_open_and_close() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut alligator_physics_system = AlligatorPhysicsSystem::new(&config, 0);
+        let mut location = Point2::new(0.0, 0.0);
+        assert_eq!(alligator_physics_system.get_state(), PhysicsState::Closed);
+
+        for _ in 0..config.alligator_closed_ticks {
+            alligator_physics_system.update(&mut location, &InputState::new(), &[]);
+        }
+        assert_eq!(alligator_physics_system.get_state(), PhysicsState::Open);
+
+        for _ in 0..config.alligator_open_ticks {
+            alligator_physics_system.update(&mut location, &InputState::new(), &[]);
+        }
+        assert_eq!(alligator_physics_system.get_state(), PhysicsState::Closed);
+    }
+
+    #[test]
+    fn ci_test_alligators_are_out_of_step() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut first = AlligatorPhysicsSystem::new(&config, 0);
+        let mut last = AlligatorPhysicsSystem::new(&config, config.alligator_count - 1);
+        let mut location = Point2::new(0.0, 0.0);
+        let mut out_of_step = 0;
+
+        for _ in 0..config.alligator_closed_ticks + config.alligator_open_ticks {
+            first.update(&mut location, &InputState::new(), &[]);
+            last.update(&mut location, &InputState::new(), &[]);
+            if first.get_state() != last.get_state() {
+                out_of_step += 1;
+            }
+        }
+        assert!(out_of_step > 0);
+    }
+}
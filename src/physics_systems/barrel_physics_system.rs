@@ -0,0 +1,68 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::game_objects::GameObject;
+use crate::handle_input::InputState;
+
+use super::{PhysicsState, PhysicsSystem};
+
+/// Rolls along the surface and comes back around from the other edge of the screen
+pub struct BarrelPhysicsSystem {
+    velocity_x: f32,
+    screen_width: f32,
+    width: f32,
+}
+
+impl BarrelPhysicsSystem {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            velocity_x: config.barrel_velocity,
+            screen_width: config.resolution_x,
+            width: config.barrel_width,
+        }
+    }
+}
+
+impl PhysicsSystem for BarrelPhysicsSystem {
+    fn update(&mut self, location: &mut Point2<f32>, _input: &InputState, _world: &[GameObject]) {
+        location.x += self.velocity_x;
+
+        if location.x + self.width / 2.0 < 0.0 {
+            location.x = self.screen_width + self.width / 2.0;
+        } else if location.x - self.width / 2.0 > self.screen_width {
+            location.x = -self.width / 2.0;
+        }
+    }
+
+    fn get_state(&self) -> PhysicsState {
+        if self.velocity_x < 0.0 {
+            PhysicsState::MovingLeft
+        } else {
+            PhysicsState::MovingRight
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_barrel_rolls_and_wraps_around() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut barrel_physics_system = BarrelPhysicsSystem::new(&config);
+        let mut location = Point2::new(config.resolution_x / 2.0, 0.0);
+
+        barrel_physics_system.update(&mut location, &InputState::new(), &[]);
+        assert_eq!(
+            location.x,
+            config.resolution_x / 2.0 + config.barrel_velocity
+        );
+        assert_eq!(barrel_physics_system.get_state(), PhysicsState::MovingLeft);
+
+        location.x = -config.barrel_width / 2.0;
+        barrel_physics_system.update(&mut location, &InputState::new(), &[]);
+        assert_eq!(location.x, config.resolution_x + config.barrel_width / 2.0);
+    }
+}
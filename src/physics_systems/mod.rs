@@ -1,23 +1,56 @@
 use ggez::nalgebra::Point2;
 
+use crate::collisions::Collision;
 use crate::game_objects::GameObject;
+use crate::handle_input::InputState;
+use crate::life_systems::DeathCause;
 
+pub mod alligator_physics_system;
+pub mod barrel_physics_system;
 pub mod player_physics_system;
+pub mod pool_physics_system;
+pub mod rope_physics_system;
+pub mod scorpion_physics_system;
 
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum PhysicsState {
+    Climbing,
+    Closed,
     Jumping,
     MovingLeft,
     MovingRight,
+    Open,
     StandingStill,
+    Swinging,
 }
 
 pub trait PhysicsSystem {
-    fn update(
-        &mut self,
-        location: &mut Point2<f32>,
-        command: Option<crate::handle_input::Command>,
-        features: Vec<GameObject>,
-    );
+    /// The world is every object on the screen, borrowed for the tick, including the one being updated
+    fn update(&mut self, location: &mut Point2<f32>, input: &InputState, world: &[GameObject]);
     fn get_state(&self) -> PhysicsState;
+
+    /// Some once per fall or hit that should cost the owner a life
+    fn take_hurt(&mut self) -> Option<DeathCause> {
+        None
+    }
+
+    /// Some for objects whose hitbox grows and shrinks
+    fn get_width(&self) -> Option<f32> {
+        None
+    }
+
+    /// True once per hit that should cost the owner points instead of a life
+    fn take_knockback(&mut self) -> bool {
+        false
+    }
+
+    fn respawn(&mut self) {}
+
+    /// Told about everything we overlap once every object has moved this tick
+    fn collided(&mut self, _location: &mut Point2<f32>, _collision: &Collision) {}
+
+    /// Catalog indexes of the relics touched since the last call
+    fn take_relics(&mut self) -> Vec<usize> {
+        vec![]
+    }
 }
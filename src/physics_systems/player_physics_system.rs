@@ -1,8 +1,12 @@
 use ggez::nalgebra::Point2;
 
+use crate::collisions::{Aabb, Collision, CollisionLayer};
 use crate::config::Config;
+use crate::game_objects::builders::pit3::pit3_offsets;
+use crate::game_objects::game_object_types::GameObjectfeatureTypes;
 use crate::game_objects::GameObject;
-use crate::handle_input::Command;
+use crate::handle_input::{Command, InputState};
+use crate::life_systems::DeathCause;
 
 use super::{PhysicsState, PhysicsSystem};
 
@@ -17,6 +21,23 @@ pub struct PlayerPhysicsSystem {
     cave_floor_y: f32,
     speed: f32,
     width: f32,
+    pit_width: f32,
+    pit3_spacing: f32,
+    falling_into: Option<DeathCause>,
+    hurt: Option<DeathCause>,
+    rope_end: Option<Point2<f32>>,
+    keep_momentum: bool,
+    can_grab_rope: bool,
+    climb_speed: f32,
+    knockback: f32,
+    knocked_back: bool,
+    knockback_taken: bool,
+    on_platform: bool,
+    touching_rope: bool,
+    letting_go: bool,
+    ladder: Option<Aabb>,
+    reaching_for_ladder: bool,
+    relics_touched: Vec<usize>,
 }
 
 impl PlayerPhysicsSystem {
@@ -32,72 +53,260 @@ impl PlayerPhysicsSystem {
             cave_floor_y: config.resolution_y - config.bedrock_height,
             speed: config.player_speed,
             width: config.player_width,
+            pit_width: config.pit_width,
+            pit3_spacing: config.pit3_spacing,
+            falling_into: None,
+            hurt: None,
+            rope_end: None,
+            keep_momentum: false,
+            can_grab_rope: true,
+            climb_speed: config.climb_speed,
+            knockback: config.barrel_knockback,
+            knocked_back: false,
+            knockback_taken: false,
+            on_platform: false,
+            touching_rope: false,
+            letting_go: false,
+            ladder: None,
+            reaching_for_ladder: false,
+            relics_touched: vec![],
         }
     }
 
-    fn collide_with_ground(&mut self, location: &mut Point2<f32>, features: Vec<GameObject>) {
-        // if we collide with a pit, then we reset floor to the cave floor
+    /// Lands on the surface or cave floor, pits and platforms reach us as collisions
+    fn land_on_floor(&mut self, location: &mut Point2<f32>) {
+        let in_cave = !self.on_surface;
         let floor_y = if self.on_surface {
             self.surface_floor_y
         } else {
             self.cave_floor_y
         };
 
-        features.iter().for_each(|feature| {
-            if let Some(feature_type) = feature.feature_type {
-                match feature_type {
-                    crate::game_objects::game_object_types::GameObjectfeatureTypes::Pit1 => {
-                        if self.on_surface
-                            && self.is_inside_horizontal(location, feature)
-                            && location.y + self.height / 2.0 >= floor_y
-                        {
-                            self.on_surface = false;
-                            self.velocity.x = 0.0;
-                        }
-                    }
+        if location.y + self.height / 2.0 > floor_y {
+            self.land(location, floor_y);
+            if in_cave {
+                if let Some(cause) = self.falling_into.take() {
+                    self.hurt = Some(cause);
                 }
             }
-        });
+        }
+    }
 
-        if location.y + self.height / 2.0 > floor_y {
-            location.y = floor_y - self.height / 2.0;
+    fn land(&mut self, location: &mut Point2<f32>, floor_y: f32) {
+        location.y = floor_y - self.height / 2.0;
+        self.velocity.y = 0.0;
+        self.keep_momentum = false;
+        self.can_grab_rope = true;
+        self.knocked_back = false;
+        self.state = if self.state != PhysicsState::Jumping {
+            self.state
+        } else if self.velocity.x > 0.0 {
+            PhysicsState::MovingRight
+        } else if self.velocity.x < 0.0 {
+            PhysicsState::MovingLeft
+        } else {
+            PhysicsState::StandingStill
+        };
+    }
+
+    fn aabb(&self, location: &Point2<f32>) -> Aabb {
+        Aabb::new(*location, self.width, self.height)
+    }
+
+    /// Coming down onto a solid lands on it, jumping up into one bumps our head,
+    /// anything else pushes us back out the way we came
+    fn collide_with_solid(&mut self, location: &mut Point2<f32>, collision: &Collision) {
+        let player = self.aabb(location);
+        let solid = collision.bounds;
+        // an earlier collision this tick may already have pushed us clear
+        if !player.overlaps(&solid) {
+            return;
+        }
+
+        if player.bottom - self.velocity.y <= solid.top {
+            self.land(location, solid.top);
+            self.on_platform = true;
+            // platforms that open up, like alligator jaws, bite whoever is standing on them
+            if collision.physics_state == Some(PhysicsState::Open) {
+                if let Some(cause) = collision.feature_type.and_then(|feature| feature.hazard()) {
+                    self.hurt = Some(cause);
+                }
+            }
+        } else if player.top - self.velocity.y >= solid.bottom {
+            location.y = solid.bottom + self.height / 2.0;
             self.velocity.y = 0.0;
-            self.state = if self.state == PhysicsState::Jumping {
-                PhysicsState::StandingStill
-            } else {
-                self.state
-            };
+        } else {
+            location.x = player.pushed_out_sideways(&solid);
         }
     }
 
-    fn is_inside_horizontal(&self, location: &mut Point2<f32>, other: &GameObject) -> bool {
-        let player_left = location.x - self.width / 2.0;
-        let player_right = location.x + self.width / 2.0;
-        let player_top = location.y - self.height / 2.0;
-        let player_bottom = location.y + self.height / 2.0;
-        let other_left = other.location.x - other.width / 2.0;
-        let other_right = other.location.x + other.width / 2.0;
-        let other_top = other.location.y - other.height / 2.0;
-        let other_bottom = other.location.y + other.height / 2.0;
+    /// Barrels send us flying away from them until we land, one hit per landing
+    fn knock_back_from(&mut self, location: &Point2<f32>, barrel: &Point2<f32>) {
+        if !self.on_surface || self.knocked_back {
+            return;
+        }
 
-        player_left > other_left && player_right < other_right
+        self.velocity.x = if location.x < barrel.x {
+            -self.knockback
+        } else {
+            self.knockback
+        };
+        self.velocity.y = -self.jump_force / 2.0;
+        self.state = PhysicsState::Jumping;
+        self.keep_momentum = true;
+        self.knocked_back = true;
+        self.knockback_taken = true;
     }
 
-    fn handle_command(&mut self, location: &mut Point2<f32>, command: Option<Command>) {
-        if let Some(command) = command {
-            match command {
-                crate::handle_input::Command::Jump => self.handle_jump_command(location),
-                crate::handle_input::Command::MoveLeft => self.handle_move_left_command(),
-                crate::handle_input::Command::MoveRight => self.handle_move_right_command(),
-                crate::handle_input::Command::StartGame => {}
-                crate::handle_input::Command::StopMovingLeft => {
-                    self.handle_stop_moving_left_command()
-                }
-                crate::handle_input::Command::StopMovingRight => {
-                    self.handle_stop_moving_right_command()
+    /// Pit3 is three openings in one feature, everything else we fall into is one wide opening
+    fn is_over_opening(&self, location: &Point2<f32>, collision: &Collision) -> bool {
+        if collision.feature_type != Some(GameObjectfeatureTypes::Pit3) {
+            return self.aabb(location).inside_horizontally(&collision.bounds);
+        }
+
+        pit3_offsets(self.pit_width, self.pit3_spacing)
+            .iter()
+            .any(|offset| {
+                self.is_inside_span(location, collision.location.x + offset, self.pit_width)
+            })
+    }
+
+    fn fall_into_pit(&mut self, location: &Point2<f32>, cause: DeathCause) {
+        if self.on_surface && location.y + self.height / 2.0 >= self.surface_floor_y {
+            self.on_surface = false;
+            self.falling_into = Some(cause);
+            self.velocity.x = 0.0;
+        }
+    }
+
+    fn is_inside_span(&self, location: &Point2<f32>, center_x: f32, width: f32) -> bool {
+        let span = Aabb::new(Point2::new(center_x, location.y), width, 0.0);
+        self.aabb(location).inside_horizontally(&span)
+    }
+
+    /// Grabs the end of the rope while jumping, then follows it until we let go
+    fn touch_rope(&mut self, location: &mut Point2<f32>, rope_end: Point2<f32>) {
+        self.touching_rope = true;
+
+        match self.state {
+            PhysicsState::Swinging if self.letting_go => {
+                // let go with whatever speed the end of the rope had
+                if let Some(last_rope_end) = self.rope_end {
+                    self.velocity = Point2::from(rope_end - last_rope_end);
                 }
+                self.let_go_of_rope();
+            }
+            PhysicsState::Swinging => self.hang_from(location, rope_end),
+            PhysicsState::Jumping if self.can_grab_rope => {
+                self.state = PhysicsState::Swinging;
+                self.velocity = Point2::new(0.0, 0.0);
+                self.hang_from(location, rope_end);
+            }
+            _ => {}
+        }
+    }
+
+    fn hang_from(&mut self, location: &mut Point2<f32>, rope_end: Point2<f32>) {
+        location.x = rope_end.x;
+        location.y = rope_end.y + self.height / 2.0;
+        self.rope_end = Some(rope_end);
+    }
+
+    fn let_go_of_rope(&mut self) {
+        self.state = PhysicsState::Jumping;
+        self.rope_end = None;
+        self.keep_momentum = true;
+        self.can_grab_rope = false;
+        self.letting_go = false;
+    }
+
+    fn reaching_for_ladder(&self, location: &Point2<f32>, input: &InputState) -> bool {
+        let feet_y = location.y + self.height / 2.0;
+
+        if self.on_surface {
+            feet_y >= self.surface_floor_y && input.is_held(Command::ClimbDown)
+        } else {
+            feet_y >= self.cave_floor_y && input.is_held(Command::ClimbUp)
+        }
+    }
+
+    /// Remembers the ladder for the next tick and gets on it if we were reaching for it
+    fn touch_ladder(&mut self, location: &mut Point2<f32>, collision: &Collision) {
+        self.ladder = Some(collision.bounds);
+
+        if self.reaching_for_ladder
+            && self.state != PhysicsState::Climbing
+            && self.aabb(location).inside_horizontally(&collision.bounds)
+        {
+            self.state = PhysicsState::Climbing;
+            self.velocity = Point2::new(0.0, 0.0);
+            location.x = collision.location.x;
+        }
+    }
+
+    fn climb(&mut self, location: &mut Point2<f32>, input: &InputState, ladder: Option<Aabb>) {
+        let on_ladder =
+            ladder.is_some_and(|ladder| self.aabb(location).inside_horizontally(&ladder));
+        if !on_ladder {
+            self.let_go_of_ladder(location);
+            return;
+        }
+
+        match (
+            input.is_held(Command::ClimbUp),
+            input.is_held(Command::ClimbDown),
+        ) {
+            (true, false) => location.y -= self.climb_speed,
+            (false, true) => location.y += self.climb_speed,
+            _ => {}
+        }
+
+        let feet_y = location.y + self.height / 2.0;
+        if feet_y >= self.cave_floor_y {
+            location.y = self.cave_floor_y - self.height / 2.0;
+            self.on_surface = false;
+            self.state = PhysicsState::StandingStill;
+        } else if feet_y <= self.surface_floor_y {
+            location.y = self.surface_floor_y - self.height / 2.0;
+            self.on_surface = true;
+            self.state = PhysicsState::StandingStill;
+        } else {
+            self.on_surface = false;
+        }
+    }
+
+    /// Off the ladder we stand if our feet are on the cave floor, otherwise we fall to the nearest floor below
+    fn let_go_of_ladder(&mut self, location: &Point2<f32>) {
+        let feet_y = location.y + self.height / 2.0;
+
+        if feet_y >= self.cave_floor_y {
+            self.on_surface = false;
+            self.state = PhysicsState::StandingStill;
+        } else if feet_y >= self.surface_floor_y {
+            self.on_surface = false;
+            self.state = PhysicsState::Jumping;
+        } else {
+            self.on_surface = true;
+            self.state = PhysicsState::Jumping;
+        }
+    }
+
+    fn handle_input(&mut self, location: &mut Point2<f32>, input: &InputState) {
+        // flying off a rope or knocked back by a barrel we keep our speed until we land
+        if !self.keep_momentum {
+            match (
+                input.is_held(Command::MoveLeft),
+                input.is_held(Command::MoveRight),
+            ) {
+                (true, false) => self.handle_move_left_command(),
+                (false, true) => self.handle_move_right_command(),
+                _ => self.handle_stop_moving_command(),
             }
         }
+
+        if input.just_pressed(Command::Jump) {
+            self.handle_jump_command(location);
+        }
     }
 
     fn handle_jump_command(&mut self, location: &mut Point2<f32>) {
@@ -106,59 +315,153 @@ impl PlayerPhysicsSystem {
         } else {
             self.cave_floor_y
         };
-        if location.y + self.height / 2.0 >= floor_y {
+        if self.on_platform || location.y + self.height / 2.0 >= floor_y {
+            self.on_platform = false;
             self.velocity.y -= self.jump_force;
             self.state = PhysicsState::Jumping;
         }
     }
 
     fn handle_move_right_command(&mut self) {
-        self.state = PhysicsState::MovingRight;
-        self.velocity.x += self.speed;
-    }
-
-    fn handle_stop_moving_right_command(&mut self) {
-        self.velocity.x = 0.0;
-        self.state = PhysicsState::StandingStill;
+        self.velocity.x = self.speed;
+        self.change_running_state(PhysicsState::MovingRight);
     }
 
     fn handle_move_left_command(&mut self) {
-        self.state = PhysicsState::MovingLeft;
-        self.velocity.x -= self.speed;
+        self.velocity.x = -self.speed;
+        self.change_running_state(PhysicsState::MovingLeft);
     }
 
-    fn handle_stop_moving_left_command(&mut self) {
-        self.state = PhysicsState::StandingStill;
+    fn handle_stop_moving_command(&mut self) {
         self.velocity.x = 0.0;
+        self.change_running_state(PhysicsState::StandingStill);
+    }
+
+    fn change_running_state(&mut self, state: PhysicsState) {
+        // we stay jumping until we land, land_on_floor or a solid picks the state up from there
+        if self.state != PhysicsState::Jumping {
+            self.state = state;
+        }
     }
 }
 
 impl PhysicsSystem for PlayerPhysicsSystem {
-    fn update(
-        &mut self,
-        location: &mut ggez::nalgebra::Point2<f32>,
-        command: Option<crate::handle_input::Command>,
-        features: Vec<crate::game_objects::GameObject>,
-    ) {
+    /// Everything we touch reaches us through collided, so the world itself isn't needed here
+    fn update(&mut self, location: &mut Point2<f32>, input: &InputState, _world: &[GameObject]) {
+        let ladder = self.ladder.take();
+        self.reaching_for_ladder = false;
+
+        if self.state == PhysicsState::Swinging {
+            if std::mem::replace(&mut self.touching_rope, false) {
+                self.letting_go = input.just_pressed(Command::Jump);
+            } else {
+                self.let_go_of_rope();
+            }
+            return;
+        }
+
+        // gravity is suspended while we're on the ladder
+        if self.state == PhysicsState::Climbing {
+            self.climb(location, input, ladder);
+            return;
+        }
+
+        self.reaching_for_ladder = self.reaching_for_ladder(location, input);
         self.velocity.y += self.gravity_force;
-        self.handle_command(location, command);
+        self.handle_input(location, input);
+        self.on_platform = false;
         location.y += self.velocity.y;
         location.x += self.velocity.x;
-        self.collide_with_ground(location, features);
+        self.land_on_floor(location);
     }
 
     fn get_state(&self) -> super::PhysicsState {
         self.state
     }
+
+    fn take_hurt(&mut self) -> Option<DeathCause> {
+        self.hurt.take()
+    }
+
+    fn take_knockback(&mut self) -> bool {
+        std::mem::replace(&mut self.knockback_taken, false)
+    }
+
+    fn collided(&mut self, location: &mut Point2<f32>, collision: &Collision) {
+        let feature_type = match collision.feature_type {
+            Some(feature_type) => feature_type,
+            None => return,
+        };
+        let hanging_on = matches!(self.state, PhysicsState::Swinging | PhysicsState::Climbing);
+
+        match (collision.layer, feature_type) {
+            (CollisionLayer::Hazard, _) => {
+                if let Some(cause) = feature_type.hazard() {
+                    self.hurt = Some(cause);
+                }
+            }
+            (_, GameObjectfeatureTypes::Relic(relic_index)) => {
+                self.relics_touched.push(relic_index)
+            }
+            (_, GameObjectfeatureTypes::Rope) => self.touch_rope(location, collision.location),
+            (_, GameObjectfeatureTypes::Ladder) => self.touch_ladder(location, collision),
+            _ if hanging_on => {}
+            (_, GameObjectfeatureTypes::Barrel) => {
+                self.knock_back_from(location, &collision.location)
+            }
+            (CollisionLayer::Solid, _) => self.collide_with_solid(location, collision),
+            (CollisionLayer::Trigger, _) => {
+                if let Some(cause) = feature_type.fall_cause() {
+                    if self.is_over_opening(location, collision) {
+                        self.fall_into_pit(location, cause);
+                    }
+                }
+            }
+        }
+    }
+
+    fn take_relics(&mut self) -> Vec<usize> {
+        std::mem::take(&mut self.relics_touched)
+    }
+
+    fn respawn(&mut self) {
+        self.state = PhysicsState::StandingStill;
+        self.velocity = Point2::new(0.0, 0.0);
+        self.on_surface = true;
+        self.falling_into = None;
+        self.hurt = None;
+        self.rope_end = None;
+        self.keep_momentum = false;
+        self.can_grab_rope = true;
+        self.knocked_back = false;
+        self.knockback_taken = false;
+        self.on_platform = false;
+        self.touching_rope = false;
+        self.letting_go = false;
+        self.ladder = None;
+        self.reaching_for_ladder = false;
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use std::slice;
+
     use ggez::nalgebra::Point2;
 
     use crate::config::Config;
+    use crate::config::WallSide;
+    use crate::game_objects::builders::alligator::create_alligators;
+    use crate::game_objects::builders::barrel::create_barrel;
+    use crate::game_objects::builders::brick_wall::create_brick_wall;
+    use crate::game_objects::builders::cave_rock::{create_stalactite, create_stalagmite};
+    use crate::game_objects::builders::hazard::{create_fire, create_snake};
+    use crate::game_objects::builders::ladder::create_ladder;
     use crate::game_objects::builders::pit1::create_pit1;
-    use crate::handle_input::Command;
+    use crate::game_objects::builders::pit3::create_pit3;
+    use crate::game_objects::builders::pool::{create_lake, create_still_lake, create_tar_pit};
+    use crate::game_objects::builders::rope::create_rope;
+    use crate::handle_input::{Command, InputState};
     use crate::physics_systems::PhysicsState;
 
     use super::*;
@@ -197,25 +500,45 @@ mod test {
     fn ci_test_player_physics_system_update() {
         let (mut player_physics_system, config) = create_player_physics_system();
         let mut location = Point2::new(0.0, config.player_starting_y);
-        let no_command = None;
+        let no_command = &InputState::new();
         let features = vec![];
-        player_physics_system.update(&mut location, no_command, features.clone());
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            no_command,
+            &features,
+        );
         assert_eq!(location, Point2::new(0.0, config.player_starting_y));
         assert_eq!(player_physics_system.velocity, Point2::new(0.0, 0.0));
 
-        let jump_command = Some(Command::Jump);
-        player_physics_system.update(&mut location, jump_command, features.clone());
+        let jump_command = &InputState::new().press(Command::Jump);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            jump_command,
+            &features,
+        );
         assert_eq!(
             player_physics_system.velocity,
             Point2::new(0.0, config.gravity_force - config.jump_force)
         );
         assert_eq!(player_physics_system.state, PhysicsState::Jumping);
-        player_physics_system.update(&mut location, no_command, features.clone());
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            no_command,
+            &features,
+        );
         assert_eq!(player_physics_system.state, PhysicsState::StandingStill);
 
         player_physics_system.on_surface = false;
         location.y = player_physics_system.cave_floor_y - player_physics_system.height / 2.0;
-        player_physics_system.update(&mut location, no_command, features);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            no_command,
+            &features,
+        );
         assert_eq!(
             location,
             Point2::new(
@@ -229,17 +552,27 @@ mod test {
     fn ci_test_player_physics_update_move_right() {
         let (mut player_physics_system, config) = create_player_physics_system();
         let mut location = Point2::new(0.0, config.player_starting_y);
-        let move_right_command = Some(Command::MoveRight);
+        let move_right_command = &InputState::new().press(Command::MoveRight);
         let features = vec![];
-        player_physics_system.update(&mut location, move_right_command, features.clone());
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            move_right_command,
+            &features,
+        );
         assert_eq!(
             location,
             Point2::new(config.player_speed, config.player_starting_y)
         );
         assert_eq!(player_physics_system.state, PhysicsState::MovingRight);
 
-        let stop_moving_right_command = Some(Command::StopMovingRight);
-        player_physics_system.update(&mut location, stop_moving_right_command, features);
+        let stop_moving_right_command = &InputState::new().release(Command::MoveRight);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            stop_moving_right_command,
+            &features,
+        );
         assert_eq!(player_physics_system.state, PhysicsState::StandingStill);
         assert_eq!(
             location,
@@ -251,17 +584,27 @@ mod test {
     fn ci_test_player_physics_update_move_left() {
         let (mut player_physics_system, config) = create_player_physics_system();
         let mut location = Point2::new(0.0, config.player_starting_y);
-        let move_left_command = Some(Command::MoveLeft);
+        let move_left_command = &InputState::new().press(Command::MoveLeft);
         let features = vec![];
-        player_physics_system.update(&mut location, move_left_command, features.clone());
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            move_left_command,
+            &features,
+        );
         assert_eq!(
             location,
             Point2::new(-config.player_speed, config.player_starting_y)
         );
         assert_eq!(player_physics_system.state, PhysicsState::MovingLeft);
 
-        let stop_moving_left_command = Some(Command::StopMovingLeft);
-        player_physics_system.update(&mut location, stop_moving_left_command, features);
+        let stop_moving_left_command = &InputState::new().release(Command::MoveLeft);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            stop_moving_left_command,
+            &features,
+        );
         assert_eq!(player_physics_system.state, PhysicsState::StandingStill);
         assert_eq!(
             location,
@@ -274,15 +617,30 @@ mod test {
         let (mut player_physics_system, config) = create_player_physics_system();
         let mut location = Point2::new(config.player_starting_x, config.player_starting_y);
         let features = vec![create_pit1(&config).unwrap()];
-        let no_command = None;
-        player_physics_system.update(&mut location, no_command, features.clone());
+        let no_command = &InputState::new();
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            no_command,
+            &features,
+        );
         assert_eq!(
             location,
             Point2::new(config.player_starting_x, config.player_starting_y)
         );
         location.x = features[0].location.x;
-        player_physics_system.update(&mut location, no_command, features.clone());
-        player_physics_system.update(&mut location, no_command, features.clone());
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            no_command,
+            &features,
+        );
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            no_command,
+            &features,
+        );
         assert_eq!(
             location,
             Point2::new(
@@ -292,8 +650,644 @@ mod test {
         );
     }
 
+    #[test]
+    fn ci_test_player_physics_landing_in_the_pit_hurts() {
+        let (mut player_physics_system, config) = create_player_physics_system();
+        let pit = create_pit1(&config).unwrap();
+        let mut location = Point2::new(pit.location.x, config.player_starting_y);
+        let features = vec![pit];
+        let no_command = &InputState::new();
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            no_command,
+            &features,
+        );
+        assert_eq!(player_physics_system.take_hurt(), None);
+
+        let hurt = (0..100).any(|_| {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                no_command,
+                &features,
+            );
+            player_physics_system.take_hurt().is_some()
+        });
+        assert!(hurt);
+        assert!(!player_physics_system.on_surface);
+        assert_eq!(player_physics_system.take_hurt(), None);
+
+        player_physics_system.respawn();
+        assert!(player_physics_system.on_surface);
+        assert_eq!(player_physics_system.velocity, Point2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn ci_test_player_physics_falls_into_any_of_three_pits() {
+        let (_, config) = create_player_physics_system();
+        let pit3 = create_pit3(&config).unwrap();
+
+        for offset in pit3_offsets(config.pit_width, config.pit3_spacing).iter() {
+            let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+            let mut location = Point2::new(pit3.location.x + offset, config.player_starting_y);
+            let features = slice::from_ref(&pit3);
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                &InputState::new(),
+                features,
+            );
+            assert!(!player_physics_system.on_surface);
+        }
+
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let between_pits = config.pit_width / 2.0 + config.pit3_spacing / 2.0;
+        let mut location = Point2::new(pit3.location.x + between_pits, config.player_starting_y);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            slice::from_ref(&pit3),
+        );
+        assert!(player_physics_system.on_surface);
+    }
+
+    #[test]
+    fn ci_test_player_physics_jumps_over_a_pit_in_pit3() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let pit3 = create_pit3(&config).unwrap();
+        let between_pits = config.pit_width / 2.0 + config.pit3_spacing / 2.0;
+        let mut location = Point2::new(pit3.location.x - between_pits, config.player_starting_y);
+        let features = slice::from_ref(&pit3);
+        let run_and_jump = &InputState::new()
+            .press(Command::Jump)
+            .hold(Command::MoveRight);
+        let keep_running = &InputState::new().hold(Command::MoveRight);
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            run_and_jump,
+            features,
+        );
+        while player_physics_system.get_state() == PhysicsState::Jumping {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                keep_running,
+                features,
+            );
+        }
+
+        assert!(player_physics_system.on_surface);
+        assert!(location.x > pit3.location.x);
+    }
+
+    #[test]
+    fn ci_test_player_physics_grab_ride_and_let_go_of_the_rope() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let mut rope = create_rope(&config).unwrap();
+        let mut location = Point2::new(rope.location.x, rope.location.y + 10.0);
+        player_physics_system.state = PhysicsState::Jumping;
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            slice::from_ref(&rope),
+        );
+        assert_eq!(player_physics_system.state, PhysicsState::Swinging);
+        assert_eq!(location.y, rope.location.y + config.player_height / 2.0);
+
+        let last_rope_end = rope.location;
+        GameObject::update_in_place(slice::from_mut(&mut rope), 0, &InputState::new());
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            slice::from_ref(&rope),
+        );
+        assert_eq!(location.x, rope.location.x);
+        let swing_speed = rope.location - last_rope_end;
+
+        let last_rope_end = rope.location;
+        GameObject::update_in_place(slice::from_mut(&mut rope), 0, &InputState::new());
+        let let_go = &InputState::new().press(Command::Jump);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            let_go,
+            slice::from_ref(&rope),
+        );
+        assert_eq!(player_physics_system.state, PhysicsState::Jumping);
+        assert_eq!(
+            player_physics_system.velocity,
+            Point2::from(rope.location - last_rope_end)
+        );
+        assert!(player_physics_system.velocity.x * swing_speed.x > 0.0);
+
+        let velocity_x = player_physics_system.velocity.x;
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            slice::from_ref(&rope),
+        );
+        assert_eq!(player_physics_system.velocity.x, velocity_x);
+        assert_eq!(player_physics_system.state, PhysicsState::Jumping);
+    }
+
+    #[test]
+    fn ci_test_player_physics_climb_down_and_back_up_the_ladder() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let ladder = create_ladder(&config).unwrap();
+        let mut location = Point2::new(ladder.location.x + 5.0, config.player_starting_y);
+        let features = slice::from_ref(&ladder);
+        let climb_down = &InputState::new().hold(Command::ClimbDown);
+        let climb_up = &InputState::new().hold(Command::ClimbUp);
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            climb_down,
+            features,
+        );
+        assert_eq!(player_physics_system.state, PhysicsState::Climbing);
+        assert_eq!(location.x, ladder.location.x);
+
+        let starting_y = location.y;
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            climb_down,
+            features,
+        );
+        assert_eq!(location.y, starting_y + config.climb_speed);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            features,
+        );
+        assert_eq!(location.y, starting_y + config.climb_speed);
+        assert_eq!(player_physics_system.state, PhysicsState::Climbing);
+        assert_eq!(player_physics_system.velocity, Point2::new(0.0, 0.0));
+
+        while player_physics_system.state == PhysicsState::Climbing {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                climb_down,
+                features,
+            );
+        }
+        assert!(!player_physics_system.on_surface);
+        assert_eq!(
+            location.y + config.player_height / 2.0,
+            player_physics_system.cave_floor_y
+        );
+        assert_eq!(player_physics_system.take_hurt(), None);
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            climb_up,
+            features,
+        );
+        assert_eq!(player_physics_system.state, PhysicsState::Climbing);
+        while player_physics_system.state == PhysicsState::Climbing {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                climb_up,
+                features,
+            );
+        }
+        assert!(player_physics_system.on_surface);
+        assert_eq!(
+            location.y + config.player_height / 2.0,
+            config.surface_floor_y
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_player_physics_no_climbing_after_leaving_the_ladder() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let ladder = create_ladder(&config).unwrap();
+        let mut location = Point2::new(ladder.location.x, config.player_starting_y);
+        let features = slice::from_ref(&ladder);
+        let climb_down = &InputState::new().hold(Command::ClimbDown);
+        let climb_up = &InputState::new().hold(Command::ClimbUp);
+
+        for _ in 0..10 {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                climb_down,
+                features,
+            );
+        }
+        assert_eq!(player_physics_system.state, PhysicsState::Climbing);
+
+        location.x = ladder.location.x + ladder.width;
+        let off_the_ladder_y = location.y;
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            climb_up,
+            features,
+        );
+        assert_eq!(player_physics_system.state, PhysicsState::Jumping);
+        assert!(!player_physics_system.on_surface);
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            climb_up,
+            features,
+        );
+        assert!(location.y > off_the_ladder_y);
+    }
+
+    #[test]
+    fn ci_test_player_physics_no_climbing_away_from_a_ladder() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let ladder = create_ladder(&config).unwrap();
+        let mut location = Point2::new(
+            ladder.location.x + config.ladder_width,
+            config.player_starting_y,
+        );
+        let climb_down = &InputState::new().hold(Command::ClimbDown);
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            climb_down,
+            slice::from_ref(&ladder),
+        );
+        assert_eq!(player_physics_system.state, PhysicsState::StandingStill);
+        assert!(player_physics_system.on_surface);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_player_physics_brick_walls_block_the_cave() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let wall = create_brick_wall(&config, WallSide::Right).unwrap();
+        let wall_left = wall.location.x - wall.width / 2.0;
+        let cave_y = player_physics_system.cave_floor_y - config.player_height / 2.0;
+        let mut location = Point2::new(wall_left - config.player_width / 2.0, cave_y);
+        let run_right = &InputState::new().hold(Command::MoveRight);
+        player_physics_system.on_surface = false;
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            run_right,
+            slice::from_ref(&wall),
+        );
+        assert_eq!(location.x, wall_left - config.player_width / 2.0);
+
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let mut location = Point2::new(wall.location.x, config.player_starting_y);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            run_right,
+            slice::from_ref(&wall),
+        );
+        assert_eq!(
+            location.x,
+            wall_left + config.brick_wall_width / 2.0 + config.player_speed
+        );
+    }
+
+    #[test]
+    fn ci_test_player_physics_barrels_knock_the_player_back() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let barrel = create_barrel(&config, 0).unwrap();
+        let mut location = Point2::new(
+            barrel.location.x - config.barrel_width / 2.0,
+            config.player_starting_y,
+        );
+        let features = slice::from_ref(&barrel);
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            features,
+        );
+        assert!(player_physics_system.take_knockback());
+        assert!(!player_physics_system.take_knockback());
+        assert_eq!(player_physics_system.state, PhysicsState::Jumping);
+        assert!(player_physics_system.velocity.x < 0.0);
+
+        let run_right = &InputState::new().hold(Command::MoveRight);
+        while player_physics_system.state == PhysicsState::Jumping {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                run_right,
+                features,
+            );
+            assert!(!player_physics_system.take_knockback());
+        }
+        assert!(location.x < barrel.location.x - config.barrel_width);
+        assert!(player_physics_system.on_surface);
+        assert_eq!(player_physics_system.take_hurt(), None);
+    }
+
+    #[test]
+    fn ci_test_player_physics_pools_only_swallow_while_open() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut tar_pit = create_tar_pit(&config).unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let mut location = Point2::new(tar_pit.location.x, config.player_starting_y);
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            slice::from_ref(&tar_pit),
+        );
+        assert!(player_physics_system.on_surface);
+
+        for _ in 0..config.pool_cycle_ticks / 2 {
+            GameObject::update_in_place(slice::from_mut(&mut tar_pit), 0, &InputState::new());
+        }
+        let hurt = (0..100).find_map(|_| {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                &InputState::new(),
+                slice::from_ref(&tar_pit),
+            );
+            player_physics_system.take_hurt()
+        });
+        assert_eq!(hurt, Some(DeathCause::TarPit));
+
+        let mut lake = create_lake(&config).unwrap();
+        for _ in 0..config.pool_cycle_ticks / 2 {
+            GameObject::update_in_place(slice::from_mut(&mut lake), 0, &InputState::new());
+        }
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let mut location = Point2::new(lake.location.x, config.player_starting_y);
+        let hurt = (0..100).find_map(|_| {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                &InputState::new(),
+                slice::from_ref(&lake),
+            );
+            player_physics_system.take_hurt()
+        });
+        assert_eq!(hurt, Some(DeathCause::Drowned));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_player_physics_stand_and_jump_on_closed_alligators() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let mut features = create_alligators(&config).unwrap();
+        let alligator = &features[0];
+        let head_top = alligator.location.y - alligator.height / 2.0;
+        let mut location = Point2::new(
+            alligator.location.x,
+            head_top - config.player_height / 2.0 - 20.0,
+        );
+        features.push(create_still_lake(&config).unwrap());
+        player_physics_system.state = PhysicsState::Jumping;
+
+        for _ in 0..30 {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                &InputState::new(),
+                &features,
+            );
+        }
+        assert_eq!(location.y + config.player_height / 2.0, head_top);
+        assert_eq!(player_physics_system.state, PhysicsState::StandingStill);
+        assert!(player_physics_system.on_surface);
+        assert_eq!(player_physics_system.take_hurt(), None);
+
+        let jump = &InputState::new().press(Command::Jump);
+        tick(&mut player_physics_system, &mut location, jump, &features);
+        assert_eq!(player_physics_system.state, PhysicsState::Jumping);
+        assert!(location.y + config.player_height / 2.0 < head_top);
+    }
+
+    #[test]
+    fn ci_test_player_physics_open_alligators_bite() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let mut alligator = create_alligators(&config).unwrap().remove(0);
+        let head_top = alligator.location.y - alligator.height / 2.0;
+        let mut location = Point2::new(alligator.location.x, head_top - config.player_height / 2.0);
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            slice::from_ref(&alligator),
+        );
+        assert_eq!(player_physics_system.take_hurt(), None);
+
+        for _ in 0..config.alligator_closed_ticks {
+            GameObject::update_in_place(slice::from_mut(&mut alligator), 0, &InputState::new());
+        }
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            slice::from_ref(&alligator),
+        );
+        assert_eq!(
+            player_physics_system.take_hurt(),
+            Some(DeathCause::Alligator)
+        );
+    }
+
+    #[test]
+    fn ci_test_player_physics_hazards_hurt_on_touch() {
+        let config = crate::config::load("config.json").unwrap();
+
+        for (hazard, cause) in [
+            (create_snake(&config).unwrap(), DeathCause::Snake),
+            (create_fire(&config).unwrap(), DeathCause::Fire),
+        ]
+        .iter()
+        {
+            let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+            let just_out_of_reach =
+                hazard.location.x - hazard.width / 2.0 - config.player_width / 2.0;
+            let mut location = Point2::new(just_out_of_reach, config.player_starting_y);
+
+            let features = slice::from_ref(hazard);
+
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                &InputState::new(),
+                features,
+            );
+            assert_eq!(player_physics_system.take_hurt(), None);
+
+            let step_in = &InputState::new().hold(Command::MoveRight);
+            tick(&mut player_physics_system, &mut location, step_in, features);
+            assert_eq!(player_physics_system.take_hurt(), Some(*cause));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_player_physics_jump_over_stalagmites() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let stalagmite = create_stalagmite(&config, 700.0).unwrap();
+        let cave_y = player_physics_system.cave_floor_y - config.player_height / 2.0;
+        let blocked_x = 700.0 - config.stalagmite_width / 2.0 - config.player_width / 2.0;
+        let mut location = Point2::new(blocked_x, cave_y);
+        let features = vec![stalagmite];
+        let run_right = &InputState::new().hold(Command::MoveRight);
+        player_physics_system.on_surface = false;
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            run_right,
+            &features,
+        );
+        assert_eq!(location.x, blocked_x);
+
+        let run_and_jump = &InputState::new()
+            .press(Command::Jump)
+            .hold(Command::MoveRight);
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            run_and_jump,
+            &features,
+        );
+        while player_physics_system.state == PhysicsState::Jumping {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                run_right,
+                &features,
+            );
+        }
+        assert!(location.x > 700.0);
+        assert!(!player_physics_system.on_surface);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_player_physics_stand_on_a_stalagmite() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let stalagmite = create_stalagmite(&config, 700.0).unwrap();
+        let top = stalagmite.location.y - stalagmite.height / 2.0;
+        let mut location = Point2::new(700.0, top - config.player_height / 2.0 - 10.0);
+        player_physics_system.on_surface = false;
+        player_physics_system.state = PhysicsState::Jumping;
+
+        for _ in 0..20 {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                &InputState::new(),
+                slice::from_ref(&stalagmite),
+            );
+        }
+        assert_eq!(location.y + config.player_height / 2.0, top);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_player_physics_stalactites_block_jumps() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut player_physics_system = PlayerPhysicsSystem::new(&config);
+        let stalactite = create_stalactite(&config, 1300.0).unwrap();
+        let bottom = stalactite.location.y + stalactite.height / 2.0;
+        let cave_y = player_physics_system.cave_floor_y - config.player_height / 2.0;
+        let mut location = Point2::new(1300.0, cave_y);
+        let features = vec![stalactite];
+        player_physics_system.on_surface = false;
+
+        tick(
+            &mut player_physics_system,
+            &mut location,
+            &InputState::new(),
+            &features,
+        );
+        assert_eq!(location.y, cave_y);
+
+        let jump = &InputState::new().press(Command::Jump);
+        tick(&mut player_physics_system, &mut location, jump, &features);
+        let mut highest_head = location.y - config.player_height / 2.0;
+        while player_physics_system.state == PhysicsState::Jumping {
+            tick(
+                &mut player_physics_system,
+                &mut location,
+                &InputState::new(),
+                &features,
+            );
+            highest_head = highest_head.min(location.y - config.player_height / 2.0);
+        }
+        assert_eq!(highest_head, bottom);
+    }
+
+    #[test]
+    fn ci_test_player_physics_run_and_jump_in_the_same_tick() {
+        let (mut player_physics_system, config) = create_player_physics_system();
+        let mut location = Point2::new(0.0, config.player_starting_y);
+        let run_and_jump = &InputState::new()
+            .press(Command::MoveRight)
+            .press(Command::Jump);
+        let keep_running = &InputState::new().hold(Command::MoveRight);
+        tick(&mut player_physics_system, &mut location, run_and_jump, &[]);
+        assert_eq!(player_physics_system.state, PhysicsState::Jumping);
+        assert_eq!(location.x, config.player_speed);
+
+        tick(&mut player_physics_system, &mut location, keep_running, &[]);
+        assert_eq!(location.x, config.player_speed * 2.0);
+        assert_eq!(player_physics_system.state, PhysicsState::MovingRight);
+    }
+
     fn create_player_physics_system() -> (PlayerPhysicsSystem, Config) {
         let config = Config::default();
         (PlayerPhysicsSystem::new(&config), config)
     }
+
+    /// One tick the way GameObjects runs it, move first and then hear about everything we overlap
+    fn tick(
+        player_physics_system: &mut PlayerPhysicsSystem,
+        location: &mut Point2<f32>,
+        input: &InputState,
+        features: &[GameObject],
+    ) {
+        player_physics_system.update(location, input, features);
+
+        for collision in features.iter().filter_map(Collision::with) {
+            if collision
+                .bounds
+                .overlaps(&player_physics_system.aabb(location))
+            {
+                player_physics_system.collided(location, &collision);
+            }
+        }
+    }
 }
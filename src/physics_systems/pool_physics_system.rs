@@ -0,0 +1,75 @@
+use std::f32::consts::PI;
+
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::game_objects::GameObject;
+use crate::handle_input::InputState;
+
+use super::{PhysicsState, PhysicsSystem};
+
+/// Opens and closes a tar pit or lake, the game object's width follows the current width
+pub struct PoolPhysicsSystem {
+    ticks: u32,
+    cycle_ticks: u32,
+    min_width: f32,
+    max_width: f32,
+}
+
+impl PoolPhysicsSystem {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            ticks: 0,
+            cycle_ticks: config.pool_cycle_ticks.max(1),
+            min_width: config.pool_min_width,
+            max_width: config.pool_max_width,
+        }
+    }
+
+    /// Eases from the min width at the start of the cycle to the max width half way through
+    pub fn current_width(&self) -> f32 {
+        let progress = self.ticks as f32 / self.cycle_ticks as f32;
+        let openness = (1.0 - (progress * 2.0 * PI).cos()) / 2.0;
+
+        self.min_width + (self.max_width - self.min_width) * openness
+    }
+}
+
+impl PhysicsSystem for PoolPhysicsSystem {
+    fn update(&mut self, _location: &mut Point2<f32>, _input: &InputState, _world: &[GameObject]) {
+        self.ticks = (self.ticks + 1) % self.cycle_ticks;
+    }
+
+    fn get_state(&self) -> PhysicsState {
+        PhysicsState::StandingStill
+    }
+
+    fn get_width(&self) -> Option<f32> {
+        Some(self.current_width())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_pool_opens_and_closes() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut pool_physics_system = PoolPhysicsSystem::new(&config);
+        let mut location = Point2::new(0.0, 0.0);
+        assert_eq!(pool_physics_system.get_width(), Some(config.pool_min_width));
+
+        for _ in 0..config.pool_cycle_ticks / 2 {
+            pool_physics_system.update(&mut location, &InputState::new(), &[]);
+        }
+        let open_width = pool_physics_system.current_width();
+        assert!((open_width - config.pool_max_width).abs() < 0.01);
+
+        for _ in 0..config.pool_cycle_ticks / 2 {
+            pool_physics_system.update(&mut location, &InputState::new(), &[]);
+        }
+        assert_eq!(pool_physics_system.current_width(), config.pool_min_width);
+    }
+}
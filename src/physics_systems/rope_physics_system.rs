@@ -0,0 +1,73 @@
+use ggez::nalgebra::Point2;
+
+use crate::config::Config;
+use crate::game_objects::GameObject;
+use crate::handle_input::InputState;
+
+use super::{PhysicsState, PhysicsSystem};
+
+/// Swings the end of the rope like a pendulum, the game object's location is the end of the rope
+pub struct RopePhysicsSystem {
+    anchor: Point2<f32>,
+    length: f32,
+    angle: f32,
+    angular_velocity: f32,
+    gravity_force: f32,
+}
+
+impl RopePhysicsSystem {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            anchor: Point2::new(config.resolution_x / 2.0, config.rope_anchor_y),
+            length: config.rope_length,
+            angle: config.rope_max_angle,
+            angular_velocity: 0.0,
+            gravity_force: config.gravity_force,
+        }
+    }
+
+    pub fn end_of_rope(&self) -> Point2<f32> {
+        Point2::new(
+            self.anchor.x + self.length * self.angle.sin(),
+            self.anchor.y + self.length * self.angle.cos(),
+        )
+    }
+}
+
+impl PhysicsSystem for RopePhysicsSystem {
+    fn update(&mut self, location: &mut Point2<f32>, _input: &InputState, _world: &[GameObject]) {
+        self.angular_velocity -= self.gravity_force / self.length * self.angle.sin();
+        self.angle += self.angular_velocity;
+        *location = self.end_of_rope();
+    }
+
+    fn get_state(&self) -> PhysicsState {
+        PhysicsState::StandingStill
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ci_test_rope_swings_back_and_forth() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut rope_physics_system = RopePhysicsSystem::new(&config);
+        let mut location = rope_physics_system.end_of_rope();
+        let mut crossed_center = false;
+
+        for _ in 0..200 {
+            rope_physics_system.update(&mut location, &InputState::new(), &[]);
+            let distance = ((location.x - rope_physics_system.anchor.x).powi(2)
+                + (location.y - rope_physics_system.anchor.y).powi(2))
+            .sqrt();
+
+            assert!((distance - config.rope_length).abs() < 0.01);
+            assert!(rope_physics_system.angle.abs() <= config.rope_max_angle + 0.05);
+            crossed_center |= location.x < rope_physics_system.anchor.x;
+        }
+
+        assert!(crossed_center);
+    }
+}
@@ -0,0 +1,171 @@
+use ggez::nalgebra::Point2;
+
+use crate::collisions::Aabb;
+use crate::config::Config;
+use crate::game_objects::{GameObject, GameObjectTypes, GameObjectfeatureTypes};
+use crate::handle_input::InputState;
+
+use super::{PhysicsState, PhysicsSystem};
+
+/// Walks back and forth along the cave floor, turning toward the player whenever they're down here
+/// too and nothing stands in the way
+pub struct ScorpionPhysicsSystem {
+    speed: f32,
+    direction: f32,
+    width: f32,
+    screen_width: f32,
+    cave_floor_y: f32,
+}
+
+impl ScorpionPhysicsSystem {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            speed: config.scorpion_speed,
+            direction: -1.0,
+            width: config.scorpion_hitbox_width,
+            screen_width: config.resolution_x,
+            cave_floor_y: config.resolution_y - config.bedrock_height,
+        }
+    }
+
+    fn player_in_cave<'a>(&self, world: &'a [GameObject]) -> Option<&'a GameObject> {
+        world.iter().find(|game_object| {
+            game_object.my_type == GameObjectTypes::Player
+                && game_object.location.y + game_object.height / 2.0 >= self.cave_floor_y - 1.0
+        })
+    }
+
+    fn blocked(&self, x: f32, world: &[GameObject]) -> bool {
+        let off_screen = x - self.width / 2.0 < 0.0 || x + self.width / 2.0 > self.screen_width;
+
+        off_screen || self.obstacle_between(x, x, world)
+    }
+
+    /// Walls and stalagmites are the only things standing on the cave floor in our way
+    fn obstacle_between(&self, from_x: f32, to_x: f32, world: &[GameObject]) -> bool {
+        let path = Aabb {
+            left: from_x.min(to_x) - self.width / 2.0,
+            right: from_x.max(to_x) + self.width / 2.0,
+            top: 0.0,
+            bottom: 0.0,
+        };
+
+        world.iter().any(|game_object| {
+            matches!(
+                game_object.feature_type,
+                Some(GameObjectfeatureTypes::BrickWall) | Some(GameObjectfeatureTypes::Stalagmite)
+            ) && path.overlaps_horizontally(&Aabb::of(game_object))
+        })
+    }
+}
+
+impl PhysicsSystem for ScorpionPhysicsSystem {
+    fn update(&mut self, location: &mut Point2<f32>, _input: &InputState, world: &[GameObject]) {
+        if let Some(player) = self.player_in_cave(world) {
+            if (player.location.x - location.x).abs() > self.speed
+                && !self.obstacle_between(location.x, player.location.x, world)
+            {
+                self.direction = (player.location.x - location.x).signum();
+            }
+        }
+
+        let next_x = location.x + self.direction * self.speed;
+        if self.blocked(next_x, world) {
+            self.direction = -self.direction;
+        } else {
+            location.x = next_x;
+        }
+    }
+
+    fn get_state(&self) -> PhysicsState {
+        if self.direction < 0.0 {
+            PhysicsState::MovingLeft
+        } else {
+            PhysicsState::MovingRight
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod test {
+    use std::slice;
+
+    use crate::config::WallSide;
+    use crate::game_objects::builders::brick_wall::create_brick_wall;
+    use crate::game_objects::builders::cave_rock::create_stalagmite;
+    use crate::game_objects::builders::player::create_player;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_scorpion_patrols_between_walls() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut scorpion_physics_system = ScorpionPhysicsSystem::new(&config);
+        let wall = create_brick_wall(&config, WallSide::Left).unwrap();
+        let mut location = Point2::new(
+            wall.location.x + wall.width / 2.0 + config.scorpion_hitbox_width / 2.0 + 1.0,
+            0.0,
+        );
+        let world = vec![wall];
+
+        scorpion_physics_system.update(&mut location, &InputState::new(), &world);
+        assert_eq!(
+            scorpion_physics_system.get_state(),
+            PhysicsState::MovingRight
+        );
+
+        let turned_at = location.x;
+        scorpion_physics_system.update(&mut location, &InputState::new(), &world);
+        assert_eq!(location.x, turned_at + config.scorpion_speed);
+    }
+
+    #[test]
+    fn ci_test_scorpion_turns_toward_the_player_in_the_cave() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut scorpion_physics_system = ScorpionPhysicsSystem::new(&config);
+        let mut location = Point2::new(config.resolution_x / 2.0, 0.0);
+        let mut player = create_player(&config).unwrap();
+        player.location.x = config.resolution_x * 0.75;
+
+        scorpion_physics_system.update(&mut location, &InputState::new(), slice::from_ref(&player));
+        assert_eq!(
+            scorpion_physics_system.get_state(),
+            PhysicsState::MovingLeft
+        );
+
+        player.location.y = scorpion_physics_system.cave_floor_y - player.height / 2.0;
+        let starting_x = location.x;
+        scorpion_physics_system.update(&mut location, &InputState::new(), slice::from_ref(&player));
+        assert_eq!(
+            scorpion_physics_system.get_state(),
+            PhysicsState::MovingRight
+        );
+        assert_eq!(location.x, starting_x + config.scorpion_speed);
+    }
+
+    #[test]
+    fn ci_test_scorpion_walks_away_from_a_player_behind_a_stalagmite() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut scorpion_physics_system = ScorpionPhysicsSystem::new(&config);
+        let stalagmite = create_stalagmite(&config, 700.0).unwrap();
+        let mut player = create_player(&config).unwrap();
+        player.location = Point2::new(
+            300.0,
+            scorpion_physics_system.cave_floor_y - player.height / 2.0,
+        );
+        let starting_x =
+            700.0 + config.stalagmite_width / 2.0 + config.scorpion_hitbox_width / 2.0 + 1.0;
+        let mut location = Point2::new(starting_x, 0.0);
+        let world = vec![stalagmite, player];
+
+        for _ in 0..10 {
+            scorpion_physics_system.update(&mut location, &InputState::new(), &world);
+        }
+        assert_eq!(
+            scorpion_physics_system.get_state(),
+            PhysicsState::MovingRight
+        );
+        assert!(location.x >= starting_x + config.scorpion_speed * 9.0);
+    }
+}
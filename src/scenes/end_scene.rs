@@ -1,27 +1,217 @@
 use ggez::{
     graphics::DrawParam,
     graphics::{self, Font, Scale, Text},
+    nalgebra::Point2,
     Context, GameResult,
 };
 
-#[derive(Default)]
+use crate::{
+    config::Config,
+    handle_input::{Command, InputState},
+    images::Images,
+    life_systems::DeathCause,
+    score::ScoreBreakdown,
+};
+
+use super::{inventory_line, start_scene::StartScene, ActiveScene, Scene, Transition};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct GameSummary {
+    pub cause: DeathCause,
+    /// None in freeplay, there's nothing to score without a timer
+    pub score: Option<ScoreBreakdown>,
+    pub relics: Vec<String>,
+    /// Seconds left on the clock, None when there wasn't a timer running
+    pub time_remaining: Option<u32>,
+}
+
+impl GameSummary {
+    fn lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            self.cause.description().to_owned(),
+            inventory_line(&self.relics),
+        ];
+
+        if let Some(time_remaining) = self.time_remaining {
+            lines.push(format!(
+                "Time remaining: {:02}:{:02}",
+                time_remaining / 60,
+                time_remaining % 60
+            ));
+        }
+
+        if let Some(score) = self.score {
+            lines.push(format!("Relic points: {}", score.relic_points));
+            if score.penalty_points > 0 {
+                lines.push(format!("Penalties: -{}", score.penalty_points));
+            }
+            lines.push(format!("Time bonus: {}", score.time_bonus));
+            lines.push(format!("Score: {}", score.total));
+        }
+
+        lines
+    }
+}
+
 pub struct EndScene {
-    text: Text,
+    title: Text,
+    summary_texts: Vec<Text>,
+    prompt: Text,
 }
 
 impl EndScene {
-    pub fn new() -> Self {
-        let mut text = Text::new("end scene");
-        text.set_font(Font::default(), Scale::uniform(72.0));
+    pub fn new(config: &Config, summary: &GameSummary) -> Self {
+        let mut title = Text::new("Game Over");
+        title.set_font(Font::default(), Scale::uniform(config.font_large));
+
+        let summary_texts = summary
+            .lines()
+            .into_iter()
+            .map(|line| {
+                let mut text = Text::new(line);
+                text.set_font(Font::default(), Scale::uniform(config.font_medium));
+                text
+            })
+            .collect();
+
+        let mut prompt = Text::new("Press select to return to the title");
+        prompt.set_font(Font::default(), Scale::uniform(config.font_small));
+
+        EndScene {
+            title,
+            summary_texts,
+            prompt,
+        }
+    }
+}
+
+impl Scene for EndScene {
+    fn update(&mut self, input: &InputState, config: &Config) -> GameResult<Transition> {
+        if input.just_pressed(Command::MenuSelect) {
+            return Ok(Transition::Replace(Box::new(StartScene::new(config))));
+        }
+
+        Ok(Transition::None)
+    }
+
+    fn draw(&mut self, context: &mut Context, config: &Config, _images: &mut Images) -> GameResult {
+        let (title_width, title_height) = self.title.dimensions(context);
+        let mut y = config.resolution_y / 4.0;
+        graphics::draw(
+            context,
+            &self.title,
+            DrawParam::new().dest(Point2::new(
+                config.resolution_x / 2.0 - title_width as f32 / 2.0,
+                y,
+            )),
+        )?;
+        y += title_height as f32 * 2.0;
+
+        for summary_text in &self.summary_texts {
+            let (text_width, text_height) = summary_text.dimensions(context);
+            graphics::draw(
+                context,
+                summary_text,
+                DrawParam::new().dest(Point2::new(
+                    config.resolution_x / 2.0 - text_width as f32 / 2.0,
+                    y,
+                )),
+            )?;
+            y += text_height as f32 * 1.5;
+        }
+
+        let prompt_position = Point2::new(
+            config.resolution_x / 2.0 - self.prompt.width(context) as f32 / 2.0,
+            config.resolution_y - config.resolution_y / 4.0,
+        );
+        graphics::draw(
+            context,
+            &self.prompt,
+            DrawParam::new().dest(prompt_position),
+        )
+    }
+
+    fn active_scene(&self) -> ActiveScene {
+        ActiveScene::End
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config;
 
-        EndScene { text }
+    use super::*;
+
+    fn summary() -> GameSummary {
+        GameSummary {
+            cause: DeathCause::Pit,
+            score: Some(ScoreBreakdown {
+                relics: 2,
+                relic_points: 2000,
+                penalty_points: 0,
+                seconds_left: 754,
+                time_bonus: 754000,
+                total: 756000,
+            }),
+            relics: vec!["Golden idol".to_owned(), "Jade mask".to_owned()],
+            time_remaining: Some(754),
+        }
     }
 
-    pub fn update(&mut self) -> GameResult {
-        Ok(())
+    #[test]
+    fn ci_test_summary_lines() {
+        assert_eq!(
+            summary().lines(),
+            vec![
+                "Fell into a pit",
+                "Relics: Golden idol, Jade mask",
+                "Time remaining: 12:34",
+                "Relic points: 2000",
+                "Time bonus: 754000",
+                "Score: 756000"
+            ]
+        );
     }
 
-    pub fn draw(&mut self, context: &mut Context) -> GameResult {
-        graphics::draw(context, &self.text, DrawParam::new())
+    #[test]
+    fn ci_test_summary_lists_penalties() {
+        let mut summary = summary();
+        if let Some(score) = &mut summary.score {
+            score.penalty_points = 300;
+            score.total -= 300;
+        }
+
+        let lines = summary.lines();
+        assert_eq!(lines[4], "Penalties: -300");
+        assert_eq!(lines[6], "Score: 755700");
+    }
+
+    #[test]
+    fn ci_test_freeplay_summary_has_no_score() {
+        let summary = GameSummary {
+            relics: vec![],
+            time_remaining: None,
+            score: None,
+            ..summary()
+        };
+
+        assert_eq!(summary.lines(), vec!["Fell into a pit", "Relics: none"]);
+    }
+
+    #[test]
+    fn ci_test_return_to_title() {
+        let config = config::load("config.json").unwrap();
+        let mut end_scene = EndScene::new(&config, &summary());
+
+        let waiting = end_scene.update(&InputState::new(), &config).unwrap();
+        assert!(matches!(waiting, Transition::None));
+
+        let transition = end_scene
+            .update(&InputState::new().press(Command::MenuSelect), &config)
+            .unwrap();
+        match transition {
+            Transition::Replace(scene) => assert_eq!(scene.active_scene(), ActiveScene::Start),
+            _ => panic!("selecting should go back to the title"),
+        }
     }
 }
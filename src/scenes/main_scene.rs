@@ -1,38 +1,78 @@
+use ggez::graphics::{self, DrawParam, Font, Scale, Text};
+use ggez::nalgebra::Point2;
 use ggez::{Context, GameResult};
 
-use crate::config::MapFeature;
+use crate::config::{CaveFeature, MapFeature};
+use crate::game_objects::builders::alligator::create_alligators;
 use crate::game_objects::builders::background::{
     create_above_ground, create_behind_ground, create_trees,
 };
+use crate::game_objects::builders::barrel::create_barrel;
+use crate::game_objects::builders::brick_wall::create_brick_wall;
+use crate::game_objects::builders::cave_rock::{create_stalactite, create_stalagmite};
+use crate::game_objects::builders::hazard::{create_fire, create_scorpion, create_snake};
 use crate::game_objects::builders::hearts::create_hearts;
+use crate::game_objects::builders::ladder::create_ladder;
 use crate::game_objects::builders::pit1::create_pit1;
+use crate::game_objects::builders::pit3::create_pit3;
 use crate::game_objects::builders::player::create_player;
-use crate::game_objects::{GameObjectTypes, GameObjects};
-use crate::{config::Config, handle_input::Command, images::Images};
+use crate::game_objects::builders::pool::{create_lake, create_still_lake, create_tar_pit};
+use crate::game_objects::builders::relic::create_relic;
+use crate::game_objects::builders::rope::create_rope;
+use crate::game_objects::{GameObject, GameObjectTypes, GameObjects};
+use crate::game_timer::GameTimer;
+use crate::life_systems::DeathCause;
+use crate::score::Score;
+use crate::{
+    config::Config,
+    handle_input::{Command, InputState},
+    images::Images,
+};
+
+use super::end_scene::{EndScene, GameSummary};
+use super::pause_scene::PauseScene;
+use super::{ActiveScene, Scene, Transition};
 
 pub struct MainScene {
     game_objects: GameObjects,
     current_screen: usize,
+    screen_changed: bool,
+    lives: u8,
+    timer: Option<GameTimer>,
+    score: Option<Score>,
+    /// Screen and catalog index of every relic picked up this run, in pickup order
+    collected_relics: Vec<(usize, usize)>,
 }
 
 impl MainScene {
-    pub fn new(config: &Config, _context: &mut Context, images: &mut Images) -> GameResult<Self> {
+    pub fn new(config: &Config) -> GameResult<Self> {
         let mut game_objects = GameObjects::new();
         let player = create_player(config).expect("error creating player");
-        let hearts = create_hearts(images, config).expect("error building hearts");
+        let hearts = create_hearts(config).expect("error building hearts");
 
-        game_objects.push(create_behind_ground(images, config).expect("error building "));
+        game_objects.push(create_behind_ground(config).expect("error building "));
         game_objects.push(create_trees().expect("Error creating trees"));
         game_objects.push(hearts);
         game_objects.push(player);
-        game_objects.push(
-            create_above_ground(images.bedrock.clone(), config)
-                .expect("error creating above background"),
-        );
+        game_objects.push(create_above_ground(config).expect("error creating above background"));
+
+        let (timer, score) = if config.freeplay {
+            (None, None)
+        } else {
+            (
+                Some(GameTimer::new(config.timer_seconds)),
+                Some(Score::new(config)),
+            )
+        };
 
         let mut main_scene = MainScene {
             game_objects,
             current_screen: config.start_index,
+            screen_changed: false,
+            lives: config.player_lives,
+            timer,
+            score,
+            collected_relics: vec![],
         };
 
         main_scene.change_screen(config);
@@ -40,70 +80,616 @@ impl MainScene {
         Ok(main_scene)
     }
 
-    pub fn update(
-        &mut self,
-        command: Option<Command>,
-        config: &Config,
-        images: &mut Images,
-        context: &mut Context,
-    ) -> GameResult {
-        self.game_objects.update(command);
+    fn change_screen(&mut self, config: &Config) {
+        self.game_objects.remove_features();
+        let player_index = self.game_objects.get_player_index().unwrap();
+        config.map[self.current_screen]
+            .iter()
+            .for_each(|map_feature| match map_feature {
+                MapFeature::Pit1 => {
+                    let pit1 = create_pit1(config).expect("error creating pit1");
+                    self.game_objects.insert(pit1, player_index);
+                }
+                MapFeature::Pit3 => {
+                    let pit3 = create_pit3(config).expect("error creating pit3");
+                    self.game_objects.insert(pit3, player_index);
+                }
+                MapFeature::TarPit => {
+                    let tar_pit = create_tar_pit(config).expect("error creating tar pit");
+                    self.game_objects.insert(tar_pit, player_index);
+                }
+                MapFeature::Lake => {
+                    let lake = create_lake(config).expect("error creating lake");
+                    self.game_objects.insert(lake, player_index);
+                }
+                MapFeature::Alligators => {
+                    // the lake goes in last so it ends up drawn under the heads
+                    let alligators = create_alligators(config).expect("error creating alligators");
+                    alligators
+                        .into_iter()
+                        .for_each(|alligator| self.game_objects.insert(alligator, player_index));
+                    let lake = create_still_lake(config).expect("error creating lake");
+                    self.game_objects.insert(lake, player_index);
+                }
+                MapFeature::Snake => {
+                    let snake = create_snake(config).expect("error creating snake");
+                    self.game_objects.insert(snake, player_index);
+                }
+                MapFeature::Fire => {
+                    let fire = create_fire(config).expect("error creating fire");
+                    self.game_objects.insert(fire, player_index);
+                }
+                MapFeature::Rope => {
+                    let rope = create_rope(config).expect("error creating rope");
+                    self.game_objects.insert(rope, player_index);
+                }
+                MapFeature::Ladder => {
+                    // drawn over the ground so the way down stays visible
+                    let ladder = create_ladder(config).expect("error creating ladder");
+                    self.game_objects.push(ladder);
+                }
+                MapFeature::Barrels(count) => {
+                    for index in 0..*count {
+                        let barrel = create_barrel(config, index).expect("error creating barrel");
+                        self.game_objects.insert(barrel, player_index);
+                    }
+                }
+                MapFeature::Relic(relic_index) => {
+                    if !self
+                        .collected_relics
+                        .contains(&(self.current_screen, *relic_index))
+                    {
+                        let relic =
+                            create_relic(config, *relic_index).expect("error creating relic");
+                        self.game_objects.insert(relic, player_index);
+                    }
+                }
+            });
+
+        let surface_has_ladder = config.map[self.current_screen].contains(&MapFeature::Ladder);
+        config.cave_map[self.current_screen / config.cave_screen_span]
+            .iter()
+            .for_each(|cave_feature| match cave_feature {
+                CaveFeature::BrickWall(side) => {
+                    let wall = create_brick_wall(config, *side).expect("error creating brick wall");
+                    self.game_objects.insert(wall, player_index);
+                }
+                CaveFeature::Stalagmite(x) => {
+                    let stalagmite =
+                        create_stalagmite(config, *x as f32).expect("error creating stalagmite");
+                    self.game_objects.insert(stalagmite, player_index);
+                }
+                CaveFeature::Stalactite(x) => {
+                    let stalactite =
+                        create_stalactite(config, *x as f32).expect("error creating stalactite");
+                    self.game_objects.insert(stalactite, player_index);
+                }
+                CaveFeature::Scorpion => {
+                    let scorpion = create_scorpion(config).expect("error creating scorpion");
+                    self.game_objects.insert(scorpion, player_index);
+                }
+                CaveFeature::Exit(offset) => {
+                    if self.current_screen % config.cave_screen_span == *offset
+                        && !surface_has_ladder
+                    {
+                        let ladder = create_ladder(config).expect("error creating ladder");
+                        self.game_objects.push(ladder);
+                    }
+                }
+            });
+    }
+
+    /// How many surface screens running off the edge moves us, the cave covers several at a time
+    fn screen_step(player: &GameObject, config: &Config) -> usize {
+        if player.location.y > config.surface_floor_y {
+            config.cave_screen_span
+        } else {
+            1
+        }
+    }
+
+    fn collect_relics(&mut self, config: &Config) {
+        for relic_index in self.game_objects.collect_relics() {
+            self.collected_relics
+                .push((self.current_screen, relic_index));
+            if let Some(score) = &mut self.score {
+                score.add_relic(config.relics[relic_index].points);
+            }
+        }
+    }
+
+    fn apply_knockbacks(&mut self, config: &Config) {
+        let knocked_back = self
+            .game_objects
+            .get_first_by_type(GameObjectTypes::Player)
+            .is_some_and(|player| player.take_knockback());
+
+        if knocked_back {
+            if let Some(score) = &mut self.score {
+                score.add_penalty(config.barrel_penalty);
+            }
+        }
+    }
+
+    fn relic_names(&self, config: &Config) -> Vec<String> {
+        self.collected_relics
+            .iter()
+            .map(|(_screen, relic_index)| config.relics[*relic_index].name.clone())
+            .collect()
+    }
+
+    fn game_over(&self, config: &Config, cause: DeathCause) -> Transition {
+        let summary = self.game_summary(config, cause);
+
+        Transition::Replace(Box::new(EndScene::new(config, &summary)))
+    }
+
+    fn game_summary(&self, config: &Config, cause: DeathCause) -> GameSummary {
+        let time_remaining = self.timer.as_ref().map(|timer| timer.seconds_left());
+        GameSummary {
+            cause,
+            score: self
+                .score
+                .as_ref()
+                .map(|score| score.breakdown(time_remaining.unwrap_or(0))),
+            relics: self.relic_names(config),
+            time_remaining,
+        }
+    }
+
+    fn update_hearts(&mut self) {
+        let lives = self
+            .game_objects
+            .get_first_by_type(GameObjectTypes::Player)
+            .and_then(|player| player.get_lives())
+            .unwrap_or(self.lives);
+
+        if lives != self.lives {
+            self.lives = lives;
+            if let Some(hearts) = self.game_objects.get_first_by_type(GameObjectTypes::Heart) {
+                hearts.lives_changed(lives);
+            }
+        }
+    }
+}
+
+impl Scene for MainScene {
+    fn update(&mut self, input: &InputState, config: &Config) -> GameResult<Transition> {
+        if input.just_pressed(Command::Pause) {
+            let relics = self.relic_names(config);
+            return Ok(Transition::Push(Box::new(PauseScene::new(config, &relics))));
+        }
+
+        self.game_objects.update(input);
+        self.update_hearts();
+        self.collect_relics(config);
+        self.apply_knockbacks(config);
+
+        if self.lives == 0 {
+            let cause = self
+                .game_objects
+                .get_first_by_type(GameObjectTypes::Player)
+                .and_then(|player| player.get_death_cause())
+                .unwrap_or(DeathCause::Pit);
+            return Ok(self.game_over(config, cause));
+        }
+
+        if let Some(timer) = &mut self.timer {
+            timer.update();
+            if timer.is_expired() {
+                return Ok(self.game_over(config, DeathCause::OutOfTime));
+            }
+        }
 
         if let Some(player) = self.game_objects.get_first_by_type(GameObjectTypes::Player) {
+            let step = Self::screen_step(player, config);
             if player.is_offscreen_right(config.resolution_x) {
-                self.current_screen += 1;
+                self.current_screen = (self.current_screen + step) % config.map.len();
                 player.location.x = 0.0;
                 self.change_screen(config);
-                images.reset_trees(context, config)?;
+                self.screen_changed = true;
             } else if player.is_offscreen_left() {
-                self.current_screen -= 1;
+                self.current_screen =
+                    (self.current_screen + config.map.len() - step) % config.map.len();
                 player.location.x = config.resolution_x;
                 self.change_screen(config);
-                images.reset_trees(context, config)?;
+                self.screen_changed = true;
             }
         }
 
-        Ok(())
+        Ok(Transition::None)
     }
 
-    pub fn draw(
-        &mut self,
-        context: &mut Context,
-        config: &Config,
-        images: &mut Images,
-    ) -> GameResult {
-        self.game_objects.draw(context, config, images)
+    fn draw(&mut self, context: &mut Context, config: &Config, images: &mut Images) -> GameResult {
+        if self.screen_changed {
+            images.reset_trees(context, config)?;
+            self.screen_changed = false;
+        }
+
+        self.game_objects.draw(context, config, images)?;
+
+        if let Some(timer) = &self.timer {
+            let mut timer_text = Text::new(timer.display());
+            timer_text.set_font(Font::default(), Scale::uniform(config.font_small));
+            graphics::draw(
+                context,
+                &timer_text,
+                DrawParam::new().dest(Point2::new(0.0, 0.0)),
+            )?;
+        }
+
+        if let Some(score) = &self.score {
+            let mut score_text = Text::new(score.current().to_string());
+            score_text.set_font(Font::default(), Scale::uniform(config.font_small));
+            let hearts_x = config.resolution_x - config.life_width * config.player_lives as f32;
+            let score_x = hearts_x - config.life_width / 2.0 - score_text.width(context) as f32;
+            graphics::draw(
+                context,
+                &score_text,
+                DrawParam::new().dest(Point2::new(score_x, 0.0)),
+            )?;
+        }
+
+        Ok(())
     }
 
-    fn change_screen(&mut self, config: &Config) {
-        let player_index = self.game_objects.get_player_index().unwrap();
-        self.game_objects.remove_features();
-        config.map[self.current_screen]
-            .iter()
-            .for_each(|map_feature| match map_feature {
-                MapFeature::Pit1 => {
-                    let pit1 = create_pit1(config).expect("error creating pit1");
-                    self.game_objects.insert(pit1, player_index);
-                }
-                MapFeature::Pit3 => {}
-                MapFeature::Rope => {}
-            });
+    fn active_scene(&self) -> ActiveScene {
+        ActiveScene::Main
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{images::Images, initialize};
+    use crate::game_objects::GameObjectfeatureTypes;
 
     use super::*;
 
     #[test]
-    fn test_create_main_scene() {
+    fn ci_test_create_main_scene() {
         let config = crate::config::load("config.json").unwrap();
-        let (context, _) = &mut initialize::initialize(&config).unwrap();
-        let mut images = Images::new(context, &config).unwrap();
-        let main_scene: MainScene = MainScene::new(&config, context, &mut images).unwrap();
+        let main_scene: MainScene = MainScene::new(&config).unwrap();
 
         assert_eq!(main_scene.current_screen, config.start_index);
+        assert!(!main_scene.screen_changed);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_run_off_right_edge_changes_screen() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+        player(&mut main_scene).location.x = config.resolution_x - config.player_width;
+
+        let running = InputState::new().hold(Command::MoveRight);
+        let mut frames = 0;
+        while main_scene.current_screen == config.start_index {
+            main_scene.update(&running, &config).unwrap();
+            frames += 1;
+            assert!(frames < 1000, "player never left the starting screen");
+        }
+
+        assert_eq!(main_scene.current_screen, config.start_index + 1);
+        assert!(main_scene.screen_changed);
+        assert_eq!(player(&mut main_scene).location.x, 0.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_run_off_left_edge_wraps_around_map() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+
+        let running = InputState::new().hold(Command::MoveLeft);
+        let mut frames = 0;
+        while main_scene.current_screen == config.start_index {
+            main_scene.update(&running, &config).unwrap();
+            frames += 1;
+            assert!(frames < 1000, "player never left the starting screen");
+        }
+
+        assert_eq!(main_scene.current_screen, config.map.len() - 1);
+        assert_eq!(player(&mut main_scene).location.x, config.resolution_x);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_falling_into_pit_costs_a_life() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+        player(&mut main_scene).location.x = config.resolution_x / 2.0;
+
+        for _ in 0..100 {
+            main_scene.update(&InputState::new(), &config).unwrap();
+        }
+
+        assert_eq!(
+            player(&mut main_scene).get_lives(),
+            Some(config.player_lives - 1)
+        );
+        assert_eq!(main_scene.lives, config.player_lives - 1);
+        assert_eq!(player(&mut main_scene).location.x, config.player_starting_x);
+        assert_eq!(player(&mut main_scene).location.y, config.player_starting_y);
+    }
+
+    #[test]
+    fn ci_test_losing_the_last_life_ends_the_game() {
+        let config = Config {
+            player_lives: 1,
+            ..crate::config::load("config.json").unwrap()
+        };
+        let mut main_scene = MainScene::new(&config).unwrap();
+        player(&mut main_scene).location.x = config.resolution_x / 2.0;
+
+        let mut frames = 0;
+        let transition = loop {
+            let transition = main_scene.update(&InputState::new(), &config).unwrap();
+            if !matches!(transition, Transition::None) {
+                break transition;
+            }
+            frames += 1;
+            assert!(frames < 1000, "the game never ended");
+        };
+
+        match transition {
+            Transition::Replace(scene) => assert_eq!(scene.active_scene(), ActiveScene::End),
+            _ => panic!("running out of lives should replace the main scene"),
+        }
+    }
+
+    #[test]
+    fn ci_test_running_out_of_time_ends_the_game() {
+        let config = Config {
+            timer_seconds: 1,
+            ..crate::config::load("config.json").unwrap()
+        };
+        let mut main_scene = MainScene::new(&config).unwrap();
+
+        for _ in 1..crate::UPDATES_PER_SECOND {
+            let transition = main_scene.update(&InputState::new(), &config).unwrap();
+            assert!(matches!(transition, Transition::None));
+        }
+
+        let transition = main_scene.update(&InputState::new(), &config).unwrap();
+        match transition {
+            Transition::Replace(scene) => assert_eq!(scene.active_scene(), ActiveScene::End),
+            _ => panic!("running out of time should end the game"),
+        }
+    }
+
+    #[test]
+    fn ci_test_freeplay_has_no_timer() {
+        let config = Config {
+            freeplay: true,
+            timer_seconds: 1,
+            ..crate::config::load("config.json").unwrap()
+        };
+        let mut main_scene = MainScene::new(&config).unwrap();
+
+        for _ in 0..crate::UPDATES_PER_SECOND * 2 {
+            let transition = main_scene.update(&InputState::new(), &config).unwrap();
+            assert!(matches!(transition, Transition::None));
+        }
+        assert!(main_scene.timer.is_none());
+    }
+
+    #[test]
+    fn ci_test_game_summary_includes_the_time_bonus() {
+        let config = crate::config::load("config.json").unwrap();
+        let main_scene = MainScene::new(&config).unwrap();
+
+        let summary = main_scene.game_summary(&config, DeathCause::Pit);
+
+        assert_eq!(summary.time_remaining, Some(config.timer_seconds));
+        assert_eq!(
+            summary.score.unwrap().total,
+            config.timer_seconds * config.score_per_second
+        );
+    }
+
+    #[test]
+    fn ci_test_freeplay_is_not_scored() {
+        let config = Config {
+            freeplay: true,
+            ..crate::config::load("config.json").unwrap()
+        };
+        let main_scene = MainScene::new(&config).unwrap();
+
+        let summary = main_scene.game_summary(&config, DeathCause::Pit);
+
+        assert_eq!(summary.score, None);
+        assert_eq!(summary.time_remaining, None);
+    }
+
+    #[test]
+    fn ci_test_collected_relics_stay_collected() {
+        let config = crate::config::load("config.json").unwrap();
+        let relic_screen = config
+            .map
+            .iter()
+            .position(|screen| screen.contains(&MapFeature::Relic(0)))
+            .unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+        main_scene.current_screen = relic_screen;
+        main_scene.change_screen(&config);
+
+        let relic_x = config.resolution_x * 0.75;
+        player(&mut main_scene).location.x = relic_x;
+        main_scene.update(&InputState::new(), &config).unwrap();
+
+        assert_eq!(main_scene.collected_relics, vec![(relic_screen, 0)]);
+        assert_eq!(
+            main_scene.score.as_ref().unwrap().current(),
+            config.relics[0].points
+        );
+        let summary = main_scene.game_summary(&config, DeathCause::Pit);
+        assert_eq!(summary.relics, vec![config.relics[0].name.clone()]);
+
+        main_scene.change_screen(&config);
+        player(&mut main_scene).location.x = 0.0;
+        main_scene.update(&InputState::new(), &config).unwrap();
+        player(&mut main_scene).location.x = relic_x;
+        main_scene.update(&InputState::new(), &config).unwrap();
+        assert_eq!(main_scene.collected_relics.len(), 1);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ci_test_cave_screens_skip_surface_screens() {
+        let config = crate::config::load("config.json").unwrap();
+        let ladder_screen = config
+            .map
+            .iter()
+            .position(|screen| screen.contains(&MapFeature::Ladder))
+            .unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+        main_scene.current_screen = ladder_screen;
+        main_scene.change_screen(&config);
+
+        let climb_down = InputState::new().hold(Command::ClimbDown);
+        player(&mut main_scene).location.x = config.resolution_x / 2.0;
+        let mut frames = 0;
+        while player(&mut main_scene).location.y + config.player_height / 2.0
+            < config.resolution_y - config.bedrock_height
+        {
+            main_scene.update(&climb_down, &config).unwrap();
+            frames += 1;
+            assert!(frames < 1000, "player never reached the cave");
+        }
+
+        player(&mut main_scene).location.x = config.resolution_x - config.player_width;
+        let running = InputState::new().hold(Command::MoveRight);
+        while main_scene.current_screen == ladder_screen {
+            main_scene.update(&running, &config).unwrap();
+            frames += 1;
+            assert!(frames < 1000, "player never left the ladder screen");
+        }
+
+        assert_eq!(
+            main_scene.current_screen,
+            (ladder_screen + config.cave_screen_span) % config.map.len()
+        );
+        assert_eq!(player(&mut main_scene).location.x, 0.0);
+        assert_eq!(main_scene.lives, config.player_lives);
+    }
+
+    #[test]
+    fn ci_test_cave_screens_load_their_own_features() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+
+        for screen in 0..config.map.len() {
+            main_scene.current_screen = screen;
+            main_scene.change_screen(&config);
+            let features: Vec<&GameObject> = main_scene.game_objects.get_all_features().collect();
+            let walls = features
+                .iter()
+                .filter(|feature| feature.feature_type == Some(GameObjectfeatureTypes::BrickWall))
+                .count();
+            let ladders = features
+                .iter()
+                .filter(|feature| feature.feature_type == Some(GameObjectfeatureTypes::Ladder))
+                .count();
+            let cave_screen = &config.cave_map[screen / config.cave_screen_span];
+            let exit_here =
+                cave_screen.contains(&CaveFeature::Exit(screen % config.cave_screen_span));
+
+            assert_eq!(
+                walls,
+                cave_screen
+                    .iter()
+                    .filter(|feature| matches!(feature, CaveFeature::BrickWall(_)))
+                    .count()
+            );
+            assert!(ladders <= 1);
+            assert_eq!(
+                ladders == 1,
+                exit_here || config.map[screen].contains(&MapFeature::Ladder)
+            );
+        }
+    }
+
+    #[test]
+    fn ci_test_barrels_cost_points_not_lives() {
+        let config = crate::config::load("config.json").unwrap();
+        let barrel_screen = config
+            .map
+            .iter()
+            .position(|screen| {
+                screen
+                    .iter()
+                    .any(|feature| matches!(feature, MapFeature::Barrels(_)))
+            })
+            .unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+        main_scene.current_screen = barrel_screen;
+        main_scene.change_screen(&config);
+        main_scene
+            .score
+            .as_mut()
+            .unwrap()
+            .add_relic(config.barrel_penalty * 2);
+
+        let barrel_x = main_scene
+            .game_objects
+            .get_all_features()
+            .find(|feature| feature.feature_type == Some(GameObjectfeatureTypes::Barrel))
+            .unwrap()
+            .location
+            .x;
+        player(&mut main_scene).location.x = barrel_x + config.barrel_velocity;
+        main_scene.update(&InputState::new(), &config).unwrap();
+
+        assert_eq!(
+            main_scene.score.as_ref().unwrap().current(),
+            config.barrel_penalty
+        );
+        assert_eq!(main_scene.lives, config.player_lives);
+    }
+
+    #[test]
+    fn ci_test_hazards_record_the_death_cause() {
+        let config = Config {
+            player_lives: 1,
+            ..crate::config::load("config.json").unwrap()
+        };
+        let snake_screen = config
+            .map
+            .iter()
+            .position(|screen| screen.contains(&MapFeature::Snake))
+            .unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+        main_scene.current_screen = snake_screen;
+        main_scene.change_screen(&config);
+        player(&mut main_scene).location.x = config.hazard_x;
+
+        main_scene.update(&InputState::new(), &config).unwrap();
+
+        assert_eq!(main_scene.lives, 0);
+        assert_eq!(
+            player(&mut main_scene).get_death_cause(),
+            Some(DeathCause::Snake)
+        );
+    }
+
+    #[test]
+    fn ci_test_pausing_freezes_the_simulation() {
+        let config = crate::config::load("config.json").unwrap();
+        let mut main_scene = MainScene::new(&config).unwrap();
+        let starting_location = player(&mut main_scene).location;
+        let pause = InputState::new()
+            .press(Command::Pause)
+            .hold(Command::MoveRight);
+
+        let transition = main_scene.update(&pause, &config).unwrap();
+
+        assert!(matches!(transition, Transition::Push(_)));
+        assert_eq!(player(&mut main_scene).location, starting_location);
+    }
+
+    fn player(main_scene: &mut MainScene) -> &mut GameObject {
+        main_scene
+            .game_objects
+            .get_first_by_type(GameObjectTypes::Player)
+            .unwrap()
     }
 }
@@ -1,9 +1,14 @@
 pub mod end_scene;
 pub mod main_scene;
 pub mod pause_scene;
+pub mod scene_stack;
 pub mod start_scene;
 
-#[derive(Eq, PartialEq, Hash, Debug)]
+use ggez::{Context, GameResult};
+
+use crate::{config::Config, handle_input::InputState, images::Images};
+
+#[derive(Eq, PartialEq, Hash, Debug, Clone, Copy)]
 #[allow(dead_code)]
 pub enum ActiveScene {
     Start,
@@ -12,14 +17,50 @@ pub enum ActiveScene {
     End,
 }
 
-impl ActiveScene {
-    pub fn change_to_main(&mut self) {
-        *self = ActiveScene::Main;
-    }
-}
-
 impl Default for ActiveScene {
     fn default() -> Self {
         ActiveScene::Start
     }
 }
+
+fn inventory_line(relics: &[String]) -> String {
+    if relics.is_empty() {
+        "Relics: none".to_owned()
+    } else {
+        format!("Relics: {}", relics.join(", "))
+    }
+}
+
+/// What the scene stack should do after a scene has updated.
+pub enum Transition {
+    None,
+    /// Put a new scene on top, the scenes below stop updating until it's popped
+    Push(Box<dyn Scene>),
+    Pop,
+    /// Swap the top scene for a new one
+    Replace(Box<dyn Scene>),
+    /// Throw away every scene on the stack and start over with this one
+    Reset(Box<dyn Scene>),
+}
+
+pub trait Scene {
+    fn update(&mut self, input: &InputState, config: &Config) -> GameResult<Transition>;
+
+    fn draw(&mut self, context: &mut Context, config: &Config, images: &mut Images) -> GameResult;
+
+    /// Which set of input bindings applies while this scene is on top
+    fn active_scene(&self) -> ActiveScene;
+
+    /// Overlays are drawn on top of the scene below them instead of replacing it
+    fn is_overlay(&self) -> bool {
+        false
+    }
+
+    fn on_enter(&mut self, _config: &Config) -> GameResult {
+        Ok(())
+    }
+
+    fn on_exit(&mut self, _config: &Config) -> GameResult {
+        Ok(())
+    }
+}
@@ -1,27 +1,232 @@
 use ggez::{
-    graphics::DrawParam,
-    graphics::{self, Font, Scale, Text},
+    graphics::{self, Color, DrawMode, DrawParam, Font, Mesh, Rect, Scale, Text, WHITE},
+    nalgebra::Point2,
     Context, GameResult,
 };
 
-#[derive(Default)]
+use crate::{
+    config::Config,
+    handle_input::{Command, InputState},
+    images::Images,
+};
+
+use super::{
+    inventory_line, main_scene::MainScene, start_scene::StartScene, ActiveScene, Scene, Transition,
+};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum PauseMenuItem {
+    Resume,
+    Restart,
+    QuitToTitle,
+}
+
+impl PauseMenuItem {
+    fn label(self) -> &'static str {
+        match self {
+            PauseMenuItem::Resume => "Resume",
+            PauseMenuItem::Restart => "Restart",
+            PauseMenuItem::QuitToTitle => "Quit to title",
+        }
+    }
+}
+
+const MENU_ITEMS: [PauseMenuItem; 3] = [
+    PauseMenuItem::Resume,
+    PauseMenuItem::Restart,
+    PauseMenuItem::QuitToTitle,
+];
+
 pub struct PauseScene {
-    text: Text,
+    title: Text,
+    menu_texts: Vec<Text>,
+    inventory: Text,
+    selected: usize,
+    overlay: Option<Mesh>,
+    selected_color: Color,
 }
 
 impl PauseScene {
-    pub fn new() -> Self {
-        let mut text = Text::new("pause scene");
-        text.set_font(Font::default(), Scale::uniform(72.0));
+    pub fn new(config: &Config, relics: &[String]) -> Self {
+        let mut title = Text::new("Paused");
+        title.set_font(Font::default(), Scale::uniform(config.font_large));
+
+        let menu_texts = MENU_ITEMS
+            .iter()
+            .map(|menu_item| {
+                let mut text = Text::new(menu_item.label());
+                text.set_font(Font::default(), Scale::uniform(config.font_medium));
+                text
+            })
+            .collect();
+
+        let mut inventory = Text::new(inventory_line(relics));
+        inventory.set_font(Font::default(), Scale::uniform(config.font_small));
+
+        PauseScene {
+            title,
+            menu_texts,
+            inventory,
+            selected: 0,
+            overlay: None,
+            selected_color: config.surface_color,
+        }
+    }
+
+    fn close(&mut self, menu_item: PauseMenuItem, config: &Config) -> GameResult<Transition> {
+        let transition = match menu_item {
+            PauseMenuItem::Resume => Transition::Pop,
+            PauseMenuItem::Restart => Transition::Reset(Box::new(MainScene::new(config)?)),
+            PauseMenuItem::QuitToTitle => Transition::Reset(Box::new(StartScene::new(config))),
+        };
+
+        Ok(transition)
+    }
+}
+
+impl Scene for PauseScene {
+    fn update(&mut self, input: &InputState, config: &Config) -> GameResult<Transition> {
+        if input.just_pressed(Command::Pause) {
+            return self.close(PauseMenuItem::Resume, config);
+        } else if input.just_pressed(Command::MenuSelect) {
+            return self.close(MENU_ITEMS[self.selected], config);
+        } else if input.just_pressed(Command::MenuDown) {
+            self.selected = (self.selected + 1) % MENU_ITEMS.len();
+        } else if input.just_pressed(Command::MenuUp) {
+            self.selected = (self.selected + MENU_ITEMS.len() - 1) % MENU_ITEMS.len();
+        }
+
+        Ok(Transition::None)
+    }
+
+    fn draw(&mut self, context: &mut Context, config: &Config, _images: &mut Images) -> GameResult {
+        if self.overlay.is_none() {
+            self.overlay = Some(Mesh::new_rectangle(
+                context,
+                DrawMode::fill(),
+                Rect::new(0.0, 0.0, config.resolution_x, config.resolution_y),
+                Color::new(0.0, 0.0, 0.0, 0.6),
+            )?);
+        }
+        if let Some(overlay) = &self.overlay {
+            graphics::draw(context, overlay, DrawParam::new())?;
+        }
 
-        PauseScene { text }
+        let (title_width, title_height) = self.title.dimensions(context);
+        let mut y = config.resolution_y / 3.0;
+        graphics::draw(
+            context,
+            &self.title,
+            DrawParam::new().dest(Point2::new(
+                config.resolution_x / 2.0 - title_width as f32 / 2.0,
+                y,
+            )),
+        )?;
+        y += title_height as f32 * 2.0;
+
+        for (index, menu_text) in self.menu_texts.iter().enumerate() {
+            let (text_width, text_height) = menu_text.dimensions(context);
+            let color = if index == self.selected {
+                self.selected_color
+            } else {
+                WHITE
+            };
+            graphics::draw(
+                context,
+                menu_text,
+                DrawParam::new()
+                    .dest(Point2::new(
+                        config.resolution_x / 2.0 - text_width as f32 / 2.0,
+                        y,
+                    ))
+                    .color(color),
+            )?;
+            y += text_height as f32 * 1.5;
+        }
+
+        let inventory_position = Point2::new(
+            config.resolution_x / 2.0 - self.inventory.width(context) as f32 / 2.0,
+            y + config.font_small,
+        );
+        graphics::draw(
+            context,
+            &self.inventory,
+            DrawParam::new().dest(inventory_position),
+        )
+    }
+
+    fn active_scene(&self) -> ActiveScene {
+        ActiveScene::Pause
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
     }
 
-    pub fn update(&mut self) -> GameResult {
+    fn on_enter(&mut self, _config: &Config) -> GameResult {
+        self.selected = 0;
         Ok(())
     }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_resume_with_pause_command() {
+        let config = config::load("config.json").unwrap();
+        let mut pause_scene = PauseScene::new(&config, &[]);
+
+        let transition = pause_scene
+            .update(&InputState::new().press(Command::Pause), &config)
+            .unwrap();
+
+        assert!(matches!(transition, Transition::Pop));
+    }
+
+    #[test]
+    fn ci_test_menu_selection_wraps() {
+        let config = config::load("config.json").unwrap();
+        let mut pause_scene = PauseScene::new(&config, &[]);
+        let menu_up = InputState::new().press(Command::MenuUp);
+        let menu_down = InputState::new().press(Command::MenuDown);
+
+        let transition = pause_scene.update(&menu_up, &config).unwrap();
+        assert!(matches!(transition, Transition::None));
+        assert_eq!(MENU_ITEMS[pause_scene.selected], PauseMenuItem::QuitToTitle);
+        pause_scene.update(&menu_down, &config).unwrap();
+        assert_eq!(MENU_ITEMS[pause_scene.selected], PauseMenuItem::Resume);
+    }
+
+    #[test]
+    fn ci_test_quit_to_title() {
+        let config = config::load("config.json").unwrap();
+        let mut pause_scene = PauseScene::new(&config, &[]);
+        let menu_up = InputState::new().press(Command::MenuUp);
+        let select = InputState::new().press(Command::MenuSelect);
+
+        pause_scene.update(&menu_up, &config).unwrap();
+        let transition = pause_scene.update(&select, &config).unwrap();
+
+        match transition {
+            Transition::Reset(scene) => assert_eq!(scene.active_scene(), ActiveScene::Start),
+            _ => panic!("quitting to title should reset the scene stack"),
+        }
+    }
+
+    #[test]
+    fn ci_test_selection_resets_when_reopened() {
+        let config = config::load("config.json").unwrap();
+        let mut pause_scene = PauseScene::new(&config, &[]);
+
+        pause_scene
+            .update(&InputState::new().press(Command::MenuDown), &config)
+            .unwrap();
+        pause_scene.on_enter(&config).unwrap();
 
-    pub fn draw(&mut self, context: &mut Context) -> GameResult {
-        graphics::draw(context, &self.text, DrawParam::new())
+        assert_eq!(pause_scene.selected, 0);
     }
 }
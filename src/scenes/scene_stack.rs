@@ -0,0 +1,167 @@
+use ggez::{Context, GameResult};
+
+use crate::{config::Config, handle_input::InputState, images::Images};
+
+use super::{ActiveScene, Scene, Transition};
+
+pub struct SceneStack {
+    scenes: Vec<Box<dyn Scene>>,
+}
+
+impl SceneStack {
+    pub fn new(mut first_scene: Box<dyn Scene>, config: &Config) -> GameResult<Self> {
+        first_scene.on_enter(config)?;
+
+        Ok(Self {
+            scenes: vec![first_scene],
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    pub fn active_scene(&self) -> ActiveScene {
+        self.scenes
+            .last()
+            .map(|scene| scene.active_scene())
+            .unwrap_or_default()
+    }
+
+    pub fn update(&mut self, input: &InputState, config: &Config) -> GameResult {
+        let transition = if let Some(scene) = self.scenes.last_mut() {
+            scene.update(input, config)?
+        } else {
+            Transition::None
+        };
+
+        self.apply(transition, config)
+    }
+
+    pub fn draw(
+        &mut self,
+        context: &mut Context,
+        config: &Config,
+        images: &mut Images,
+    ) -> GameResult {
+        let first_visible = self.first_visible_index();
+        self.scenes[first_visible..]
+            .iter_mut()
+            .try_for_each(|scene| scene.draw(context, config, images))
+    }
+
+    fn first_visible_index(&self) -> usize {
+        self.scenes
+            .iter()
+            .rposition(|scene| !scene.is_overlay())
+            .unwrap_or(0)
+    }
+
+    fn apply(&mut self, transition: Transition, config: &Config) -> GameResult {
+        match transition {
+            Transition::None => {}
+            Transition::Push(scene) => self.push(scene, config)?,
+            Transition::Pop => self.pop(config)?,
+            Transition::Replace(scene) => {
+                self.pop(config)?;
+                self.push(scene, config)?;
+            }
+            Transition::Reset(scene) => {
+                while !self.scenes.is_empty() {
+                    self.pop(config)?;
+                }
+                self.push(scene, config)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push(&mut self, mut scene: Box<dyn Scene>, config: &Config) -> GameResult {
+        scene.on_enter(config)?;
+        self.scenes.push(scene);
+        Ok(())
+    }
+
+    fn pop(&mut self, config: &Config) -> GameResult {
+        if let Some(mut scene) = self.scenes.pop() {
+            scene.on_exit(config)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config;
+    use crate::handle_input::Command;
+    use crate::scenes::start_scene::StartScene;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_starting_the_game_replaces_the_start_scene() {
+        let config = config::load("config.json").unwrap();
+        let mut scenes = SceneStack::new(Box::new(StartScene::new(&config)), &config).unwrap();
+        assert_eq!(scenes.active_scene(), ActiveScene::Start);
+
+        scenes
+            .update(&InputState::new().press(Command::StartGame), &config)
+            .unwrap();
+
+        assert_eq!(scenes.active_scene(), ActiveScene::Main);
+        assert_eq!(scenes.scenes.len(), 1);
+    }
+
+    #[test]
+    fn ci_test_pause_is_an_overlay_on_the_main_scene() {
+        let config = config::load("config.json").unwrap();
+        let mut scenes = SceneStack::new(Box::new(StartScene::new(&config)), &config).unwrap();
+        let start = InputState::new().press(Command::StartGame);
+        let pause = InputState::new().press(Command::Pause);
+
+        scenes.update(&start, &config).unwrap();
+        scenes.update(&pause, &config).unwrap();
+
+        assert_eq!(scenes.active_scene(), ActiveScene::Pause);
+        assert_eq!(scenes.scenes.len(), 2);
+        assert_eq!(scenes.first_visible_index(), 0);
+
+        scenes.update(&pause, &config).unwrap();
+        assert_eq!(scenes.active_scene(), ActiveScene::Main);
+        assert_eq!(scenes.scenes.len(), 1);
+    }
+
+    #[test]
+    fn ci_test_quitting_to_title_clears_the_stack() {
+        let config = config::load("config.json").unwrap();
+        let mut scenes = SceneStack::new(Box::new(StartScene::new(&config)), &config).unwrap();
+
+        scenes
+            .update(&InputState::new().press(Command::StartGame), &config)
+            .unwrap();
+        scenes
+            .update(&InputState::new().press(Command::Pause), &config)
+            .unwrap();
+        scenes
+            .update(&InputState::new().press(Command::MenuUp), &config)
+            .unwrap();
+        scenes
+            .update(&InputState::new().press(Command::MenuSelect), &config)
+            .unwrap();
+
+        assert_eq!(scenes.active_scene(), ActiveScene::Start);
+        assert_eq!(scenes.scenes.len(), 1);
+    }
+
+    #[test]
+    fn ci_test_popping_the_last_scene_empties_the_stack() {
+        let config = config::load("config.json").unwrap();
+        let mut scenes = SceneStack::new(Box::new(StartScene::new(&config)), &config).unwrap();
+
+        scenes.apply(Transition::Pop, &config).unwrap();
+
+        assert!(scenes.is_empty());
+        assert_eq!(scenes.active_scene(), ActiveScene::Start);
+    }
+}
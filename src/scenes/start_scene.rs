@@ -5,64 +5,90 @@ use ggez::{
     Context, GameResult,
 };
 
-use crate::{config::Config, handle_input::Command};
+use crate::{
+    config::Config,
+    handle_input::{Command, InputState},
+    images::Images,
+};
+
+use super::{main_scene::MainScene, ActiveScene, Scene, Transition};
 
 pub struct StartScene {
     title: Text,
-    title_position: Point2<f32>,
     subtitle: Text,
-    subtitle_position: Point2<f32>,
 }
 
 impl StartScene {
-    pub fn new(config: &Config, context: &mut Context) -> Self {
+    pub fn new(config: &Config) -> Self {
         let mut title = Text::new(config.title.clone());
         title.set_font(Font::default(), Scale::uniform(config.font_large));
-        let (title_width, title_height) = title.dimensions(context);
-        let title_position = Point2::new(
-            config.resolution_x / 2.0 - (title_width / 2) as f32,
-            config.resolution_y / 2.0 - (title_height / 2) as f32,
-        );
 
         let mut subtitle = Text::new(config.title_subtext.clone());
         subtitle.set_font(Font::default(), Scale::uniform(config.font_small));
-        let subtitle_position = Point2::new(
-            config.resolution_x / 2.0 - subtitle.width(context) as f32 / 2.0,
-            config.resolution_y - config.resolution_y / 4.0,
-        );
 
-        StartScene {
-            title,
-            title_position,
-            subtitle,
-            subtitle_position,
-        }
+        StartScene { title, subtitle }
     }
+}
 
-    pub fn update(
-        &mut self,
-        command: Option<Command>,
-        active_scene: &mut super::ActiveScene,
-    ) -> GameResult {
-        if let Some(command) = command {
-            if matches!(command, Command::StartGame) {
-                active_scene.change_to_main();
-            }
+impl Scene for StartScene {
+    fn update(&mut self, input: &InputState, config: &Config) -> GameResult<Transition> {
+        if input.just_pressed(Command::StartGame) {
+            return Ok(Transition::Replace(Box::new(MainScene::new(config)?)));
         }
-        Ok(())
+
+        Ok(Transition::None)
     }
 
-    pub fn draw(&mut self, context: &mut Context) -> GameResult {
-        graphics::draw(
-            context,
-            &self.title,
-            DrawParam::new().dest(self.title_position),
-        )?;
+    fn draw(&mut self, context: &mut Context, config: &Config, _images: &mut Images) -> GameResult {
+        let (title_width, title_height) = self.title.dimensions(context);
+        let title_position = Point2::new(
+            config.resolution_x / 2.0 - (title_width / 2) as f32,
+            config.resolution_y / 2.0 - (title_height / 2) as f32,
+        );
+        let subtitle_position = Point2::new(
+            config.resolution_x / 2.0 - self.subtitle.width(context) as f32 / 2.0,
+            config.resolution_y - config.resolution_y / 4.0,
+        );
+
+        graphics::draw(context, &self.title, DrawParam::new().dest(title_position))?;
 
         graphics::draw(
             context,
             &self.subtitle,
-            DrawParam::new().dest(self.subtitle_position),
+            DrawParam::new().dest(subtitle_position),
         )
     }
+
+    fn active_scene(&self) -> ActiveScene {
+        ActiveScene::Start
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config;
+
+    use super::*;
+
+    #[test]
+    fn ci_test_start_game() {
+        let config = config::load("config.json").unwrap();
+        let mut start_scene = StartScene::new(&config);
+
+        let transition = start_scene
+            .update(&InputState::new().press(Command::StartGame), &config)
+            .unwrap();
+
+        assert!(matches!(transition, Transition::Replace(_)));
+    }
+
+    #[test]
+    fn ci_test_waiting_on_the_title() {
+        let config = config::load("config.json").unwrap();
+        let mut start_scene = StartScene::new(&config);
+
+        let transition = start_scene.update(&InputState::new(), &config).unwrap();
+
+        assert!(matches!(transition, Transition::None));
+    }
 }
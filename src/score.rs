@@ -0,0 +1,108 @@
+use crate::config::Config;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct ScoreBreakdown {
+    pub relics: u32,
+    pub relic_points: u32,
+    pub penalty_points: u32,
+    pub seconds_left: u32,
+    pub time_bonus: u32,
+    pub total: u32,
+}
+
+pub struct Score {
+    per_second: u32,
+    relics: u32,
+    relic_points: u32,
+    penalty_points: u32,
+}
+
+impl Score {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            per_second: config.score_per_second,
+            relics: 0,
+            relic_points: 0,
+            penalty_points: 0,
+        }
+    }
+
+    pub fn add_relic(&mut self, points: u32) {
+        self.relics += 1;
+        self.relic_points += points;
+    }
+
+    pub fn add_penalty(&mut self, points: u32) {
+        self.penalty_points += points;
+    }
+
+    /// Points earned so far, the time bonus is only added at the end of the game
+    pub fn current(&self) -> u32 {
+        self.relic_points.saturating_sub(self.penalty_points)
+    }
+
+    pub fn breakdown(&self, seconds_left: u32) -> ScoreBreakdown {
+        let time_bonus = seconds_left * self.per_second;
+
+        ScoreBreakdown {
+            relics: self.relics,
+            relic_points: self.relic_points,
+            penalty_points: self.penalty_points,
+            seconds_left,
+            time_bonus,
+            total: (self.relic_points + time_bonus).saturating_sub(self.penalty_points),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ci_test_new_score_is_zero() {
+        let score = Score::new(&Config::default());
+
+        assert_eq!(score.current(), 0);
+    }
+
+    #[test]
+    fn ci_test_relics_and_time_bonus() {
+        let config = Config::default();
+        let mut score = Score::new(&config);
+
+        score.add_relic(1000);
+        score.add_relic(500);
+
+        assert_eq!(score.current(), 1500);
+        assert_eq!(
+            score.breakdown(30),
+            ScoreBreakdown {
+                relics: 2,
+                relic_points: 1500,
+                penalty_points: 0,
+                seconds_left: 30,
+                time_bonus: 30 * config.score_per_second,
+                total: 1500 + 30 * config.score_per_second,
+            }
+        );
+    }
+
+    #[test]
+    fn ci_test_penalties_never_go_below_zero() {
+        let config = Config::default();
+        let mut score = Score::new(&config);
+
+        score.add_relic(1000);
+        score.add_penalty(400);
+        assert_eq!(score.current(), 600);
+
+        score.add_penalty(1000);
+        assert_eq!(score.current(), 0);
+        assert_eq!(score.breakdown(0).total, 0);
+        assert_eq!(
+            score.breakdown(1).total,
+            1000 + config.score_per_second - 1400
+        );
+    }
+}